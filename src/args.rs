@@ -1,6 +1,10 @@
-use crate::config::{DescriptionCase, InitOption, ParsedCommitDisplayFormat};
+use crate::config::{
+    BodyLengthExemption, ColorChoice, Convention, DescriptionCase, HeaderPatternsMatch, InitOption,
+    Language, ParsedCommitDisplayFormat, ScopeCase,
+};
 use crate::lint::constants::config_descriptions;
-use clap::{builder::ArgPredicate, Parser};
+use crate::stats::StatsFormat;
+use clap::{builder::ArgPredicate, Args, Parser, Subcommand};
 use clap_complete::Shell;
 
 #[derive(Parser, Debug)]
@@ -9,8 +13,12 @@ use clap_complete::Shell;
     version = env!("CARGO_PKG_VERSION"),
     about = env!("CARGO_PKG_DESCRIPTION"),
     after_help = "Visit https://sumi.rs to learn more about git-sumi",
+    args_conflicts_with_subcommands = true,
 )]
 pub struct Opt {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     #[arg(index = 1, help = config_descriptions::COMMIT_MESSAGE)]
     pub commit_message: Option<String>,
 
@@ -71,6 +79,16 @@ pub struct Opt {
     )]
     pub split_lines: Option<bool>,
 
+    /// Fix the commit header when possible.
+    #[arg(
+        long,
+        env = "GIT_SUMI_FIX",
+        num_args = 0,
+        default_missing_value = "true",
+        help = config_descriptions::FIX.short
+    )]
+    pub fix: Option<bool>,
+
     /// Display the parsed commit message.
     #[arg(
         short = 'd',
@@ -83,7 +101,7 @@ pub struct Opt {
     pub display: Option<bool>,
 
     /// Specify the output format for displaying the parsed commit message.
-    /// Options: "cli", "table", "json", "toml". Default: "cli"
+    /// Options: "cli", "table", "json", "ndjson", "toml". Default: "cli"
     #[arg(short = 'f',
         long,
         env = "GIT_SUMI_FORMAT",
@@ -93,6 +111,18 @@ pub struct Opt {
     )]
     pub format: Option<ParsedCommitDisplayFormat>,
 
+    /// Color error diagnostics.
+    /// Options: "auto", "always", "never". Default: "auto"
+    #[arg(
+        long,
+        env = "GIT_SUMI_COLOR",
+        value_name = "WHEN",
+        value_enum,
+        ignore_case = true,
+        help = config_descriptions::COLOR.short
+    )]
+    pub color: Option<ColorChoice>,
+
     /// Lint all commits in a revision range (from..to).
     #[arg(
         long,
@@ -104,7 +134,6 @@ pub struct Opt {
     )]
     pub from: Option<String>,
 
-    /// End of the revision range.
     #[arg(
         long,
         value_name = "REV",
@@ -115,6 +144,43 @@ pub struct Opt {
     )]
     pub to: Option<String>,
 
+    /// Authors whose commits are skipped in a revision range.
+    #[arg(
+        long,
+        env = "GIT_SUMI_SKIP_AUTHORS",
+        value_name = "AUTHORS",
+        help = config_descriptions::SKIP_AUTHORS.short
+    )]
+    pub skip_authors: Vec<String>,
+
+    /// Skip merge commits in a revision range.
+    #[arg(
+        long,
+        env = "GIT_SUMI_SKIP_MERGE_COMMITS",
+        num_args = 0,
+        default_missing_value = "true",
+        help = config_descriptions::SKIP_MERGE_COMMITS.short
+    )]
+    pub skip_merge_commits: Option<bool>,
+
+    /// Rules that can't be disabled with a Sumi-Disable trailer.
+    #[arg(
+        long,
+        env = "GIT_SUMI_UNSUPPRESSIBLE_RULES",
+        value_name = "RULES",
+        help = config_descriptions::UNSUPPRESSIBLE_RULES.short
+    )]
+    pub unsuppressible_rules: Vec<String>,
+
+    /// Baseline file of violations accepted in a revision range.
+    #[arg(
+        long,
+        env = "GIT_SUMI_BASELINE",
+        value_name = "FILE",
+        help = config_descriptions::BASELINE.short
+    )]
+    pub baseline: Option<String>,
+
     /// Commit the message after successful linting.
     #[arg(short = 'c', long, help=config_descriptions::COMMIT)]
     pub commit: bool,
@@ -132,12 +198,36 @@ pub struct Opt {
         default_value_ifs([
             ("types_allowed", ArgPredicate::IsPresent, Some("true")),
             ("scopes_allowed", ArgPredicate::IsPresent, Some("true")),
+            ("body_required_types", ArgPredicate::IsPresent, Some("true")),
+            ("lowercase_type", ArgPredicate::IsPresent, Some("true")),
+            ("scope_required", ArgPredicate::IsPresent, Some("true")),
+            ("scope_required_types", ArgPredicate::IsPresent, Some("true")),
+            ("scope_case", ArgPredicate::IsPresent, Some("true")),
+            ("no_multiple_scopes", ArgPredicate::IsPresent, Some("true")),
+            ("single_space_after_colon", ArgPredicate::IsPresent, Some("true")),
+            ("breaking_requires_footer", ArgPredicate::IsPresent, Some("true")),
+            ("breaking_requires_bang", ArgPredicate::IsPresent, Some("true")),
+            ("min_breaking_description_length", ArgPredicate::IsPresent, Some("true")),
+            ("breaking_forbidden_types", ArgPredicate::IsPresent, Some("true")),
+            ("breaking_forbidden_scopes", ArgPredicate::IsPresent, Some("true")),
+            ("breaking_forbidden_branches", ArgPredicate::IsPresent, Some("true")),
             ]),
         help_heading = "Rules",
         help = config_descriptions::CONVENTIONAL.short
     )]
     pub conventional: Option<bool>,
 
+    /// Commit message convention to follow.
+    #[arg(
+        long,
+        env = "GIT_SUMI_CONVENTION",
+        value_enum,
+        ignore_case = true,
+        help_heading = "Rules",
+        help = config_descriptions::CONVENTION.short
+    )]
+    pub convention: Option<Convention>,
+
     /// Use the imperative mood in the description.
     #[arg(
         short = 'I',
@@ -150,6 +240,19 @@ pub struct Opt {
     )]
     pub imperative: Option<bool>,
 
+    /// Language of the commit messages for the imperative rule.
+    /// Options: "de", "en", "es". Default: "en".
+    #[arg(
+        long,
+        env = "GIT_SUMI_LANGUAGE",
+        value_name = "LANGUAGE",
+        value_enum,
+        ignore_case = true,
+        help_heading = "Rules",
+        help = config_descriptions::LANGUAGE.short
+    )]
+    pub language: Option<Language>,
+
     /// Include one valid Gitmoji.
     #[arg(
         short = 'G',
@@ -200,6 +303,39 @@ pub struct Opt {
     )]
     pub no_period: Option<bool>,
 
+    /// Check the spelling of the description and body.
+    #[arg(
+        long,
+        env = "GIT_SUMI_SPELLING",
+        num_args = 0,
+        default_missing_value = "true",
+        help_heading = "Rules",
+        help = config_descriptions::SPELLING.short
+    )]
+    pub spelling: Option<bool>,
+
+    /// Reject placeholder words, profanity and non-inclusive terms.
+    #[arg(
+        long,
+        env = "GIT_SUMI_FORBIDDEN_WORDS",
+        num_args = 0,
+        default_missing_value = "true",
+        default_value_if("forbidden_words_extra", ArgPredicate::IsPresent, Some("true")),
+        help_heading = "Rules",
+        help = config_descriptions::FORBIDDEN_WORDS.short
+    )]
+    pub forbidden_words: Option<bool>,
+
+    /// Additional, comma-separated forbidden words.
+    #[arg(
+        long,
+        env = "GIT_SUMI_FORBIDDEN_WORDS_EXTRA",
+        value_name = "WORDS",
+        help_heading = "Rules",
+        help = config_descriptions::FORBIDDEN_WORDS_EXTRA.short
+    )]
+    pub forbidden_words_extra: Vec<String>,
+
     /// Limit the header to the specified length.
     #[arg(short = 'H',
         long,
@@ -210,6 +346,39 @@ pub struct Opt {
     )]
     pub max_header_length: Option<usize>,
 
+    /// Require the description to have at least the specified length.
+    #[arg(
+        long,
+        env = "GIT_SUMI_MIN_DESCRIPTION_LENGTH",
+        value_name = "LENGTH",
+        value_parser = clap::value_parser!(usize),
+        help_heading = "Rules",
+        help = config_descriptions::MIN_DESCRIPTION_LENGTH.short
+    )]
+    pub min_description_length: Option<usize>,
+
+    /// Require the description to have at least the specified number of words.
+    #[arg(
+        long,
+        env = "GIT_SUMI_MIN_DESCRIPTION_WORDS",
+        value_name = "WORDS",
+        value_parser = clap::value_parser!(usize),
+        help_heading = "Rules",
+        help = config_descriptions::MIN_DESCRIPTION_WORDS.short
+    )]
+    pub min_description_words: Option<usize>,
+
+    /// Reject vague descriptions like 'update' or 'misc'.
+    #[arg(
+        long,
+        env = "GIT_SUMI_NO_VAGUE_DESCRIPTION",
+        num_args = 0,
+        default_missing_value = "true",
+        help_heading = "Rules",
+        help = config_descriptions::NO_VAGUE_DESCRIPTION.short
+    )]
+    pub no_vague_description: Option<bool>,
+
     /// Wrap the body at the specified length.
     #[arg(short = 'B',
         long,
@@ -220,6 +389,83 @@ pub struct Opt {
     )]
     pub max_body_length: Option<usize>,
 
+    /// Exempt the specified, comma-separated kinds of body lines from the length limit.
+    #[arg(
+        long,
+        env = "GIT_SUMI_BODY_LENGTH_EXEMPTIONS",
+        value_name = "EXEMPTIONS",
+        value_enum,
+        value_delimiter = ',',
+        ignore_case = true,
+        help_heading = "Rules",
+        help = config_descriptions::BODY_LENGTH_EXEMPTIONS.short
+    )]
+    pub body_length_exemptions: Vec<BodyLengthExemption>,
+
+    /// Require a body for the specified, comma-separated commit types.
+    #[arg(
+        long,
+        env = "GIT_SUMI_BODY_REQUIRED_TYPES",
+        value_name = "TYPES",
+        help_heading = "Rules",
+        help = config_descriptions::BODY_REQUIRED_TYPES.short
+    )]
+    pub body_required_types: Vec<String>,
+
+    /// Require a body when the header is longer than the specified length.
+    #[arg(
+        long,
+        env = "GIT_SUMI_BODY_REQUIRED_HEADER_LENGTH",
+        value_name = "LENGTH",
+        value_parser = clap::value_parser!(usize),
+        help_heading = "Rules",
+        help = config_descriptions::BODY_REQUIRED_HEADER_LENGTH.short
+    )]
+    pub body_required_header_length: Option<usize>,
+
+    /// Require a body when the specified, comma-separated header pattern captures match.
+    #[arg(
+        long,
+        env = "GIT_SUMI_BODY_REQUIRED_CAPTURES",
+        value_name = "NAMES",
+        help_heading = "Rules",
+        help = config_descriptions::BODY_REQUIRED_CAPTURES.short
+    )]
+    pub body_required_captures: Vec<String>,
+
+    /// Require the body to have at least the specified length.
+    #[arg(
+        long,
+        env = "GIT_SUMI_MIN_BODY_LENGTH",
+        value_name = "LENGTH",
+        value_parser = clap::value_parser!(usize),
+        help_heading = "Rules",
+        help = config_descriptions::MIN_BODY_LENGTH.short
+    )]
+    pub min_body_length: Option<usize>,
+
+    /// Body must not just repeat the header.
+    #[arg(
+        long,
+        env = "GIT_SUMI_NO_REPEATED_HEADER",
+        num_args = 0,
+        default_missing_value = "true",
+        help_heading = "Rules",
+        help = config_descriptions::NO_REPEATED_HEADER.short
+    )]
+    pub no_repeated_header: Option<bool>,
+
+    /// Use the same marker for all bullets in a list.
+    #[arg(
+        long,
+        env = "GIT_SUMI_CONSISTENT_BULLETS",
+        num_args = 0,
+        default_missing_value = "true",
+        help_heading = "Rules",
+        help = config_descriptions::CONSISTENT_BULLETS.short
+    )]
+    pub consistent_bullets: Option<bool>,
+
     /// Only allow the specified, comma-separated commit scopes.
     #[arg(
         short = 'S',
@@ -242,6 +488,155 @@ pub struct Opt {
     )]
     pub types_allowed: Vec<String>,
 
+    /// Commit type must be lowercase.
+    #[arg(
+        long,
+        env = "GIT_SUMI_LOWERCASE_TYPE",
+        num_args = 0,
+        default_missing_value = "true",
+        help_heading = "Rules",
+        help = config_descriptions::LOWERCASE_TYPE.short
+    )]
+    pub lowercase_type: Option<bool>,
+
+    /// Require a commit scope.
+    #[arg(
+        long,
+        env = "GIT_SUMI_SCOPE_REQUIRED",
+        num_args = 0,
+        default_missing_value = "true",
+        help_heading = "Rules",
+        help = config_descriptions::SCOPE_REQUIRED.short
+    )]
+    pub scope_required: Option<bool>,
+
+    /// Require a scope for the specified, comma-separated commit types.
+    #[arg(
+        long,
+        env = "GIT_SUMI_SCOPE_REQUIRED_TYPES",
+        value_name = "TYPES",
+        help_heading = "Rules",
+        help = config_descriptions::SCOPE_REQUIRED_TYPES.short
+    )]
+    pub scope_required_types: Vec<String>,
+
+    /// Case style of the commit scope.
+    #[arg(
+        long,
+        env = "GIT_SUMI_SCOPE_CASE",
+        value_name = "CASE",
+        value_enum,
+        ignore_case = true,
+        help_heading = "Rules",
+        help = config_descriptions::SCOPE_CASE.short
+    )]
+    pub scope_case: Option<ScopeCase>,
+
+    /// Allow only one commit scope.
+    #[arg(
+        long,
+        env = "GIT_SUMI_NO_MULTIPLE_SCOPES",
+        num_args = 0,
+        default_missing_value = "true",
+        help_heading = "Rules",
+        help = config_descriptions::NO_MULTIPLE_SCOPES.short
+    )]
+    pub no_multiple_scopes: Option<bool>,
+
+    /// Characters that separate multiple scopes.
+    #[arg(
+        long,
+        env = "GIT_SUMI_SCOPE_DELIMITERS",
+        value_name = "CHARACTERS",
+        help_heading = "Rules",
+        help = config_descriptions::SCOPE_DELIMITERS.short
+    )]
+    pub scope_delimiters: Option<String>,
+
+    /// Separator between the components of a hierarchical scope.
+    #[arg(
+        long,
+        env = "GIT_SUMI_SCOPE_HIERARCHY_SEPARATOR",
+        value_name = "SEPARATOR",
+        help_heading = "Rules",
+        help = config_descriptions::SCOPE_HIERARCHY_SEPARATOR.short
+    )]
+    pub scope_hierarchy_separator: Option<String>,
+
+    /// Require exactly one space after the colon in the header.
+    #[arg(
+        long,
+        env = "GIT_SUMI_SINGLE_SPACE_AFTER_COLON",
+        num_args = 0,
+        default_missing_value = "true",
+        help_heading = "Rules",
+        help = config_descriptions::SINGLE_SPACE_AFTER_COLON.short
+    )]
+    pub single_space_after_colon: Option<bool>,
+
+    /// Require a 'BREAKING CHANGE:' footer when the header uses '!'.
+    #[arg(
+        long,
+        env = "GIT_SUMI_BREAKING_REQUIRES_FOOTER",
+        num_args = 0,
+        default_missing_value = "true",
+        help_heading = "Rules",
+        help = config_descriptions::BREAKING_REQUIRES_FOOTER.short
+    )]
+    pub breaking_requires_footer: Option<bool>,
+
+    /// Require '!' in the header when there's a 'BREAKING CHANGE:' footer.
+    #[arg(
+        long,
+        env = "GIT_SUMI_BREAKING_REQUIRES_BANG",
+        num_args = 0,
+        default_missing_value = "true",
+        help_heading = "Rules",
+        help = config_descriptions::BREAKING_REQUIRES_BANG.short
+    )]
+    pub breaking_requires_bang: Option<bool>,
+
+    /// Require the breaking change description to have at least the specified length.
+    #[arg(
+        long,
+        env = "GIT_SUMI_MIN_BREAKING_DESCRIPTION_LENGTH",
+        value_name = "LENGTH",
+        value_parser = clap::value_parser!(usize),
+        help_heading = "Rules",
+        help = config_descriptions::MIN_BREAKING_DESCRIPTION_LENGTH.short
+    )]
+    pub min_breaking_description_length: Option<usize>,
+
+    /// Forbid breaking changes for the specified, comma-separated commit types.
+    #[arg(
+        long,
+        env = "GIT_SUMI_BREAKING_FORBIDDEN_TYPES",
+        value_name = "TYPES",
+        help_heading = "Rules",
+        help = config_descriptions::BREAKING_FORBIDDEN_TYPES.short
+    )]
+    pub breaking_forbidden_types: Vec<String>,
+
+    /// Forbid breaking changes for the specified, comma-separated scopes.
+    #[arg(
+        long,
+        env = "GIT_SUMI_BREAKING_FORBIDDEN_SCOPES",
+        value_name = "SCOPES",
+        help_heading = "Rules",
+        help = config_descriptions::BREAKING_FORBIDDEN_SCOPES.short
+    )]
+    pub breaking_forbidden_scopes: Vec<String>,
+
+    /// Forbid breaking changes on the specified, comma-separated branches.
+    #[arg(
+        long,
+        env = "GIT_SUMI_BREAKING_FORBIDDEN_BRANCHES",
+        value_name = "BRANCHES",
+        help_heading = "Rules",
+        help = config_descriptions::BREAKING_FORBIDDEN_BRANCHES.short
+    )]
+    pub breaking_forbidden_branches: Vec<String>,
+
     /// Commit header must match the specified (regex) pattern.
     #[arg(
         short = 'R',
@@ -264,6 +659,122 @@ pub struct Opt {
         help = config_descriptions::STRIP_HEADER_PATTERN.short
     )]
     pub strip_header_pattern: Option<bool>,
+
+    /// Whether headers must match all or any of the header patterns.
+    #[arg(
+        long,
+        env = "GIT_SUMI_HEADER_PATTERNS_MATCH",
+        value_name = "MODE",
+        value_enum,
+        ignore_case = true,
+        help_heading = "Rules",
+        help = config_descriptions::HEADER_PATTERNS_MATCH.short
+    )]
+    pub header_patterns_match: Option<HeaderPatternsMatch>,
+
+    /// Commit header must follow the specified template.
+    #[arg(
+        long,
+        env = "GIT_SUMI_HEADER_FORMAT",
+        value_name = "TEMPLATE",
+        help_heading = "Rules",
+        help = config_descriptions::HEADER_FORMAT.short
+    )]
+    pub header_format: Option<String>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Generate a changelog from the commits in a revision range.
+    Changelog(ChangelogArgs),
+    /// Compute the next semantic version from the commits since the latest version tag.
+    NextVersion(NextVersionArgs),
+    /// Report statistics on the commits in a revision range.
+    Stats(StatsArgs),
+    /// Write the violations in a revision range to a baseline file, to accept them in range linting.
+    Baseline(BaselineArgs),
+}
+
+/// The revision range of a subcommand.
+#[derive(Args, Debug)]
+pub struct RangeArgs {
+    #[arg(
+        long,
+        value_name = "REV",
+        value_parser = non_empty_string,
+        help = "Start of the revision range (exclusive). Default: the first commit"
+    )]
+    pub from: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "REV",
+        default_value = "HEAD",
+        value_parser = non_empty_string,
+        help = "End of the revision range (inclusive)"
+    )]
+    pub to: String,
+}
+
+impl RangeArgs {
+    /// The range as given to git, like 'v1.0.0..HEAD'.
+    pub fn range(&self) -> String {
+        match &self.from {
+            Some(from) => format!("{from}..{}", self.to),
+            None => self.to.clone(),
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct ChangelogArgs {
+    #[command(flatten)]
+    pub range: RangeArgs,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Template file for the changelog, with '{range}' and '{sections}' placeholders"
+    )]
+    pub template: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct NextVersionArgs {
+    #[arg(
+        long,
+        help = "Print the current and next versions with the commits that justify the bump as JSON"
+    )]
+    pub json: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct StatsArgs {
+    #[command(flatten)]
+    pub range: RangeArgs,
+
+    #[arg(
+        short,
+        long,
+        value_name = "FORMAT",
+        default_value = "table",
+        help = "Output format"
+    )]
+    pub format: StatsFormat,
+}
+
+#[derive(Args, Debug)]
+pub struct BaselineArgs {
+    #[command(flatten)]
+    pub range: RangeArgs,
+
+    #[arg(
+        short,
+        long,
+        value_name = "FILE",
+        help = "Baseline file to write. Default: the 'baseline' option, or '.sumi-baseline.toml'"
+    )]
+    pub output: Option<String>,
 }
 
 fn non_empty_string(s: &str) -> Result<String, String> {
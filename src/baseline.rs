@@ -0,0 +1,100 @@
+use crate::config::Config;
+use crate::errors::SumiError;
+use crate::git::GitCommit;
+use crate::lint::{lint_silently, messages_to_lint};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Where `git-sumi baseline` writes, unless `--output` or the `baseline` option says otherwise.
+pub const DEFAULT_BASELINE_PATH: &str = ".sumi-baseline.toml";
+
+/// Rule id for errors that don't belong to a rule, like an empty header.
+const OTHER_RULE: &str = "other";
+
+const BASELINE_HEADER: &str = "\
+# Violations accepted when linting a commit range, written by `git-sumi baseline`.
+# Each commit lists the rules it may break. Any other violation still fails.
+
+";
+
+/// Violations accepted in a revision range: rule ids by commit SHA.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Baseline {
+    pub commits: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Baseline {
+    /// Lints the commits as in range linting and records every violation.
+    pub fn collect(commits: &[GitCommit], config: &Config) -> Self {
+        let mut baseline = Baseline::default();
+        for commit in commits {
+            if config.skips_commit(&commit.metadata) {
+                continue;
+            }
+            let config = config.for_commit(&commit.metadata);
+            for message in messages_to_lint(commit, config) {
+                for err in &lint_silently(message, config).errors {
+                    baseline
+                        .commits
+                        .entry(commit.sha.clone())
+                        .or_default()
+                        .insert(rule_id(err).to_string());
+                }
+            }
+        }
+        baseline
+    }
+
+    /// The baseline file set in the `baseline` option, if any.
+    pub fn from_config(config: &Config) -> Result<Option<Self>, SumiError> {
+        if config.baseline.is_empty() {
+            return Ok(None);
+        }
+        Self::load(&config.baseline).map(Some)
+    }
+
+    fn load(path: &str) -> Result<Self, SumiError> {
+        let invalid_baseline = |detail: String| SumiError::InvalidBaseline {
+            path: path.to_string(),
+            detail,
+        };
+        let content = std::fs::read_to_string(path).map_err(|e| invalid_baseline(e.to_string()))?;
+        toml::from_str(&content).map_err(|e| invalid_baseline(e.message().to_string()))
+    }
+
+    pub fn write(&self, path: &str) -> Result<(), SumiError> {
+        let content = format!("{BASELINE_HEADER}{}", toml::to_string(self)?);
+        std::fs::write(path, content).map_err(|e| SumiError::GeneralError {
+            details: format!("Could not write the baseline to '{path}': {e}"),
+        })
+    }
+
+    pub fn violation_count(&self) -> usize {
+        self.commits.values().map(BTreeSet::len).sum()
+    }
+
+    /// The rules the commit may break.
+    pub fn accepted_rules(&self, sha: &str) -> Option<&BTreeSet<String>> {
+        self.commits.get(sha)
+    }
+}
+
+/// Moves the errors of the accepted rules out of `errors`.
+pub fn accept_baselined(
+    errors: &mut Vec<SumiError>,
+    accepted: Option<&BTreeSet<String>>,
+) -> Vec<SumiError> {
+    let Some(accepted) = accepted else {
+        return Vec::new();
+    };
+    let (baselined, remaining) = std::mem::take(errors)
+        .into_iter()
+        .partition(|err| accepted.contains(rule_id(err)));
+    *errors = remaining;
+    baselined
+}
+
+fn rule_id(err: &SumiError) -> &'static str {
+    err.rule().unwrap_or(OTHER_RULE)
+}
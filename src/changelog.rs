@@ -0,0 +1,163 @@
+use crate::config::{ChangelogSection, Config};
+use crate::git::GitCommit;
+use crate::lint::canonical_type;
+use crate::parser::{parse_history, ParsedCommit};
+use regex::Regex;
+use std::sync::LazyLock;
+
+const DEFAULT_TEMPLATE: &str = "## {range}\n\n{sections}\n";
+const DEFAULT_BREAKING_TITLE: &str = "⚠️ Breaking changes";
+const DEFAULT_OTHER_TITLE: &str = "Other changes";
+
+/// Renders the changelog of the commits, oldest first.
+/// `template` defaults to a Markdown heading for the range followed by the sections.
+pub fn generate_changelog(
+    commits: &[GitCommit],
+    config: &Config,
+    range: &str,
+    template: Option<&str>,
+) -> String {
+    let parsed_commits = parse_history(commits, config);
+    template
+        .unwrap_or(DEFAULT_TEMPLATE)
+        .replace("{range}", range)
+        .replace(
+            "{sections}",
+            render_sections(&parsed_commits, config).trim_end(),
+        )
+}
+
+fn render_sections(commits: &[(String, ParsedCommit)], config: &Config) -> String {
+    let changelog = &config.changelog;
+    let mut output = String::new();
+
+    let breaking_title = changelog
+        .breaking_title
+        .as_deref()
+        .unwrap_or(DEFAULT_BREAKING_TITLE);
+    let breaking: Vec<String> = commits
+        .iter()
+        .filter(|(_, commit)| commit.is_breaking == Some(true))
+        .map(|(sha, commit)| {
+            let description = commit
+                .breaking_description
+                .as_deref()
+                .unwrap_or(&commit.description);
+            render_entry(sha, commit, description, config)
+        })
+        .collect();
+    push_section(&mut output, breaking_title, &breaking);
+
+    let sections = if changelog.sections.is_empty() {
+        default_sections()
+    } else {
+        changelog.sections.clone()
+    };
+    let commit_type = |commit: &ParsedCommit| {
+        let commit_type = commit.commit_type.as_deref().unwrap_or_default();
+        canonical_type(config, commit_type)
+            .unwrap_or(commit_type)
+            .to_string()
+    };
+    for section in &sections {
+        let entries: Vec<String> = commits
+            .iter()
+            .filter(|(_, commit)| section.types.contains(&commit_type(commit)))
+            .map(|(sha, commit)| render_entry(sha, commit, &commit.description, config))
+            .collect();
+        push_section(&mut output, &section.title, &entries);
+    }
+
+    let other_title = changelog
+        .other_title
+        .as_deref()
+        .unwrap_or(DEFAULT_OTHER_TITLE);
+    let others: Vec<String> = commits
+        .iter()
+        .filter(|(_, commit)| {
+            let commit_type = commit_type(commit);
+            !sections.iter().any(|s| s.types.contains(&commit_type))
+        })
+        .map(|(sha, commit)| render_entry(sha, commit, &commit.description, config))
+        .collect();
+    push_section(&mut output, other_title, &others);
+
+    output
+}
+
+fn default_sections() -> Vec<ChangelogSection> {
+    vec![
+        ChangelogSection {
+            title: "Features".to_string(),
+            types: vec!["feat".to_string()],
+        },
+        ChangelogSection {
+            title: "Bug fixes".to_string(),
+            types: vec!["fix".to_string()],
+        },
+    ]
+}
+
+/// Appends a section, unless it has no title or no entries.
+fn push_section(output: &mut String, title: &str, entries: &[String]) {
+    if title.is_empty() || entries.is_empty() {
+        return;
+    }
+    output.push_str(&format!("### {title}\n\n{}\n\n", entries.join("\n")));
+}
+
+fn render_entry(sha: &str, commit: &ParsedCommit, description: &str, config: &Config) -> String {
+    let short_sha = &sha[..7.min(sha.len())];
+    // References in the header are already part of the description.
+    let references = commit
+        .references
+        .iter()
+        .flatten()
+        .filter(|reference| !commit.header.contains(reference.as_str()))
+        .map(|reference| link_reference(reference, &config.changelog.reference_url))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let entry_format = &config.changelog.entry_format;
+    if !entry_format.is_empty() {
+        return entry_format
+            .replace("{sha}", short_sha)
+            .replace("{type}", commit.commit_type.as_deref().unwrap_or_default())
+            .replace("{scope}", commit.scope.as_deref().unwrap_or_default())
+            .replace("{description}", description)
+            .replace("{references}", &references)
+            .trim_end()
+            .to_string();
+    }
+
+    let mut entry = String::from("- ");
+    if let Some(scope) = &commit.scope {
+        entry.push_str(&format!("**{scope}:** "));
+    }
+    entry.push_str(description);
+    if !references.is_empty() {
+        entry.push_str(&format!(" ({references})"));
+    }
+    entry.push_str(&format!(" ({short_sha})"));
+    entry
+}
+
+static LINKABLE_REFERENCE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    // Regex: issue/PR numbers like "#12" and tickets like "PROJ-123", but not commit SHAs.
+    Regex::new(r"^(?:#(?P<number>\d+)|[A-Z][A-Z0-9_]+-\d+)$").expect("Failed to compile regex")
+});
+
+/// Links issue numbers and tickets with `reference_url`, replacing '{reference}'
+/// with the number (without '#') or the ticket.
+fn link_reference(reference: &str, reference_url: &str) -> String {
+    match LINKABLE_REFERENCE_REGEX.captures(reference) {
+        Some(caps) if !reference_url.is_empty() => {
+            let value = caps.name("number").map_or(reference, |m| m.as_str());
+            format!(
+                "[{reference}]({})",
+                reference_url.replace("{reference}", value)
+            )
+        }
+        _ => reference.to_string(),
+    }
+}
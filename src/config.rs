@@ -1,17 +1,23 @@
 use crate::lint::constants::config_descriptions::*;
 use clap::ValueEnum;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
 use std::path::{Path, PathBuf};
 
 use super::SumiError;
 use crate::args::Opt;
+use crate::git::{get_current_branch, CommitMetadata};
+use crate::lint::forbidden_words::ForbiddenWords;
+use crate::lint::{matches_wildcard, split_and_trim_list};
+use crate::parser::header_format::HeaderFormat;
 
 const CONFIG_FILE_NAME: &str = "sumi.toml";
+const PROJECT_DICTIONARY_FILE_NAME: &str = ".sumi-words.txt";
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 #[serde(default)]
@@ -19,19 +25,216 @@ pub struct Config {
     pub quiet: bool,
     pub display: bool,
     pub format: ParsedCommitDisplayFormat,
+    pub color: ColorChoice,
     pub split_lines: bool,
+    pub fix: bool,
+    pub skip_authors: Vec<String>,
+    pub skip_merge_commits: bool,
+    pub unsuppressible_rules: Vec<String>,
+    pub baseline: String,
     pub gitmoji: bool,
     pub description_case: DescriptionCase,
     pub imperative: bool,
+    pub language: Language,
     pub no_period: bool,
+    pub spelling: bool,
+    pub forbidden_words: bool,
+    pub forbidden_words_extra: Vec<String>,
     pub max_header_length: usize,
+    pub min_description_length: usize,
+    pub min_description_words: usize,
+    pub no_vague_description: bool,
     pub max_body_length: usize,
+    pub body_length_exemptions: Vec<BodyLengthExemption>,
+    pub body_required_types: Vec<String>,
+    pub body_required_header_length: usize,
+    pub body_required_captures: Vec<String>,
+    pub min_body_length: usize,
+    pub no_repeated_header: bool,
+    pub consistent_bullets: bool,
     pub whitespace: bool,
     pub conventional: bool,
+    pub convention: Convention,
     pub scopes_allowed: Vec<String>,
     pub types_allowed: Vec<String>,
+    pub lowercase_type: bool,
+    pub scope_required: bool,
+    pub scope_required_types: Vec<String>,
+    pub scope_case: ScopeCase,
+    pub no_multiple_scopes: bool,
+    pub scope_delimiters: String,
+    pub scope_hierarchy_separator: String,
+    pub single_space_after_colon: bool,
+    pub breaking_requires_footer: bool,
+    pub breaking_requires_bang: bool,
+    pub min_breaking_description_length: usize,
+    pub breaking_forbidden_types: Vec<String>,
+    pub breaking_forbidden_scopes: Vec<String>,
+    pub breaking_forbidden_branches: Vec<String>,
     pub header_pattern: String,
     pub strip_header_pattern: bool,
+    pub header_format: String,
+    pub header_patterns_match: HeaderPatternsMatch,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub types: BTreeMap<String, TypeDefinition>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub scopes: BTreeMap<String, ScopeDefinition>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub header_patterns: Vec<HeaderPattern>,
+    #[serde(skip_serializing_if = "ChangelogConfig::is_default")]
+    pub changelog: ChangelogConfig,
+    #[serde(skip_serializing_if = "VersionConfig::is_default")]
+    pub version: VersionConfig,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub author_overrides: Vec<AuthorOverride>,
+    /// `header_pattern` and `header_patterns`, compiled once when the config is assembled.
+    #[serde(skip)]
+    pub compiled_header_patterns: Vec<CompiledHeaderPattern>,
+    /// `header_format`, compiled once when the config is assembled.
+    #[serde(skip)]
+    pub(crate) compiled_header_format: Option<HeaderFormat>,
+    /// The forbidden words, if the rule is enabled, compiled once when the config is assembled.
+    #[serde(skip)]
+    pub(crate) compiled_forbidden_words: Option<ForbiddenWords>,
+    /// `author_overrides`, each applied to this config when the config is assembled.
+    #[serde(skip)]
+    pub compiled_author_overrides: Vec<CompiledAuthorOverride>,
+    /// The checked out branch, resolved once when the config is assembled
+    /// if any configuration sets `breaking_forbidden_branches`.
+    #[serde(skip)]
+    pub(crate) current_branch: Option<String>,
+}
+
+/// A commit type defined in a `[types.<name>]` table.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct TypeDefinition {
+    pub description: String,
+    /// Alternative names, fixed to the canonical type with `fix`.
+    pub aliases: Vec<String>,
+    /// Version bump for commits of this type.
+    pub bump: Option<VersionBump>,
+}
+
+/// A commit scope defined in a `[scopes.<name>]` table.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScopeDefinition {
+    pub description: String,
+    /// Alternative names, fixed to the canonical scope with `fix`.
+    pub aliases: Vec<String>,
+}
+
+/// A header pattern defined in a `[[header_patterns]]` table.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct HeaderPattern {
+    pub pattern: String,
+    /// Shown instead of the pattern when the header doesn't match.
+    pub message: Option<String>,
+}
+
+/// Options for the commits of some authors, defined in an `[[author_overrides]]` table.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct AuthorOverride {
+    /// Author names or emails. '*' matches any sequence of characters.
+    pub authors: Vec<String>,
+    /// Options that replace the configured ones, like `max_header_length = 100`.
+    #[serde(flatten)]
+    pub options: toml::Table,
+}
+
+/// The authors of an `[[author_overrides]]` table, with the configuration for their commits.
+#[derive(Debug, Clone)]
+pub struct CompiledAuthorOverride {
+    pub authors: Vec<String>,
+    pub config: Config,
+}
+
+/// Options of `git-sumi changelog`, set in the `[changelog]` table.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct ChangelogConfig {
+    /// Sections in order, defined in `[[changelog.sections]]` tables.
+    /// Defaults to "Features" (`feat`) and "Bug fixes" (`fix`).
+    pub sections: Vec<ChangelogSection>,
+    /// Title of the section for commits of other types. An empty title leaves them out.
+    pub other_title: Option<String>,
+    /// Title of the section listing breaking changes. An empty title leaves it out.
+    pub breaking_title: Option<String>,
+    /// Format of each entry, with placeholders like '{description}'. Empty for the default format.
+    pub entry_format: String,
+    /// URL for issue references and tickets, with a '{reference}' placeholder.
+    pub reference_url: String,
+}
+
+impl ChangelogConfig {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// A changelog section defined in a `[[changelog.sections]]` table.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct ChangelogSection {
+    pub title: String,
+    /// Commit types listed in the section.
+    pub types: Vec<String>,
+}
+
+/// Options of `git-sumi next-version`, set in the `[version]` table.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct VersionConfig {
+    /// Version bump by gitmoji name, like 'sparkles'. Extends and overrides the defaults.
+    pub gitmoji_bumps: BTreeMap<String, VersionBump>,
+    /// While the major version is 0, breaking changes bump the minor version
+    /// and features bump the patch version.
+    pub initial_development: bool,
+}
+
+impl Default for VersionConfig {
+    fn default() -> Self {
+        VersionConfig {
+            gitmoji_bumps: BTreeMap::new(),
+            initial_development: true,
+        }
+    }
+}
+
+impl VersionConfig {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// A header pattern with its compiled regex.
+#[derive(Debug, Clone)]
+pub struct CompiledHeaderPattern {
+    pub pattern: String,
+    pub regex: Regex,
+    pub message: Option<String>,
+}
+
+/// Whether headers must match all or any of the header patterns.
+#[derive(Debug, Clone, Serialize, Deserialize, ValueEnum, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum HeaderPatternsMatch {
+    #[default]
+    All,
+    Any,
+}
+
+/// Semantic Versioning component bumped by a commit.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum VersionBump {
+    None,
+    Patch,
+    Minor,
+    Major,
 }
 
 pub trait Configurable {
@@ -45,10 +248,44 @@ pub enum ParsedCommitDisplayFormat {
     #[default]
     Cli,
     Json,
+    /// One compact JSON object per line.
+    Ndjson,
     Table,
     Toml,
 }
 
+impl ParsedCommitDisplayFormat {
+    /// Whether the format serializes the parsed commit. In range mode, commits
+    /// displayed in these formats are gathered into a single document.
+    pub fn is_document(&self) -> bool {
+        matches!(self, Self::Json | Self::Ndjson | Self::Toml)
+    }
+}
+
+/// When to color diagnostics.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ValueEnum, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorChoice {
+    /// Color when stderr is a terminal and `NO_COLOR` isn't set.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    pub fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+                    && std::io::stderr().is_terminal()
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ValueEnum, PartialEq, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum DescriptionCase {
@@ -58,6 +295,78 @@ pub enum DescriptionCase {
     Upper,
 }
 
+/// Commit message convention used to parse the header.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ValueEnum, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Convention {
+    /// Follow the `conventional` rule.
+    #[default]
+    None,
+    /// 'type(scope): description'.
+    Conventional,
+    /// Conventional Commits with the Angular types.
+    Angular,
+    /// 'subsystem: description'.
+    Kernel,
+    /// '<gitmoji> description'.
+    Gitmoji,
+    /// 'PROJ-123: description'.
+    Jira,
+    /// '[component] description'.
+    Chromium,
+}
+
+impl Convention {
+    /// Name of the convention for error messages.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Convention::None => "basic",
+            Convention::Conventional => "conventional",
+            Convention::Angular => "Angular",
+            Convention::Kernel => "Linux kernel",
+            Convention::Gitmoji => "gitmoji",
+            Convention::Jira => "Jira",
+            Convention::Chromium => "Chromium",
+        }
+    }
+}
+
+/// Case style of the commit scope.
+#[derive(Debug, Clone, Serialize, Deserialize, ValueEnum, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ScopeCase {
+    #[default]
+    Any,
+    /// No uppercase letters.
+    Lower,
+    /// Lowercase words separated by hyphens, like 'user-auth'.
+    Kebab,
+}
+
+/// Body lines that don't count towards `max_body_length`.
+#[derive(Debug, Clone, Serialize, Deserialize, ValueEnum, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum BodyLengthExemption {
+    /// Lines consisting of a single URL.
+    Url,
+    /// Lines inside fenced (```) or indented code blocks.
+    Code,
+    /// Quoted lines, starting with '>'.
+    Quote,
+    /// Footer and trailer lines, like 'Signed-off-by: …'.
+    Trailer,
+}
+
+/// Language of the commit messages, used by the imperative rule.
+#[derive(Debug, Clone, Serialize, Deserialize, ValueEnum, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    De,
+    #[default]
+    En,
+    Es,
+}
+
 /// Options to initialise git-sumi config.
 #[derive(Debug, Clone, Serialize, Deserialize, ValueEnum)]
 pub enum InitOption {
@@ -71,6 +380,7 @@ type IsModifiedFn<'a> = Box<dyn Fn(&Config, &Config) -> bool + 'a>;
 type CurrentValueFn<'a> = Box<dyn Fn(&Config) -> String + 'a>;
 
 struct RuleMeta<'a> {
+    name: &'static str,
     is_modified: IsModifiedFn<'a>,
     description: &'a str,
     current_value: CurrentValueFn<'a>,
@@ -79,31 +389,67 @@ struct RuleMeta<'a> {
 fn rules_metadata<'a>() -> Vec<RuleMeta<'a>> {
     vec![
         RuleMeta {
+            name: "conventional",
             is_modified: Box::new(|c, d| c.conventional != d.conventional),
             description: CONVENTIONAL.short,
             current_value: Box::new(|c| c.conventional.to_string()),
         },
         RuleMeta {
+            name: "convention",
+            is_modified: Box::new(|c, d| c.convention != d.convention),
+            description: CONVENTION.short,
+            current_value: Box::new(|c| {
+                c.convention
+                    .to_possible_value()
+                    .unwrap()
+                    .get_name()
+                    .to_string()
+            }),
+        },
+        RuleMeta {
+            name: "imperative",
             is_modified: Box::new(|c, d| c.imperative != d.imperative),
             description: IMPERATIVE.short,
             current_value: Box::new(|c| c.imperative.to_string()),
         },
         RuleMeta {
+            name: "gitmoji",
             is_modified: Box::new(|c, d| c.gitmoji != d.gitmoji),
             description: GITMOJI.short,
             current_value: Box::new(|c| c.gitmoji.to_string()),
         },
         RuleMeta {
+            name: "whitespace",
             is_modified: Box::new(|c, d| c.whitespace != d.whitespace),
             description: WHITESPACE.short,
             current_value: Box::new(|c| c.whitespace.to_string()),
         },
         RuleMeta {
+            name: "no_period",
             is_modified: Box::new(|c, d| c.no_period != d.no_period),
             description: NO_PERIOD.short,
             current_value: Box::new(|c| c.no_period.to_string()),
         },
         RuleMeta {
+            name: "spelling",
+            is_modified: Box::new(|c, d| c.spelling != d.spelling),
+            description: SPELLING.short,
+            current_value: Box::new(|c| c.spelling.to_string()),
+        },
+        RuleMeta {
+            name: "forbidden_words",
+            is_modified: Box::new(|c, d| c.forbidden_words != d.forbidden_words),
+            description: FORBIDDEN_WORDS.short,
+            current_value: Box::new(|c| c.forbidden_words.to_string()),
+        },
+        RuleMeta {
+            name: "forbidden_words_extra",
+            is_modified: Box::new(|c, d| c.forbidden_words_extra != d.forbidden_words_extra),
+            description: FORBIDDEN_WORDS_EXTRA.short,
+            current_value: Box::new(|c| c.forbidden_words_extra.join(", ")),
+        },
+        RuleMeta {
+            name: "description_case",
             is_modified: Box::new(|c, d| c.description_case != d.description_case),
             description: DESCRIPTION_CASE.short,
             current_value: Box::new(|c| {
@@ -115,38 +461,276 @@ fn rules_metadata<'a>() -> Vec<RuleMeta<'a>> {
             }),
         },
         RuleMeta {
+            name: "max_header_length",
             is_modified: Box::new(|c, d| c.max_header_length != d.max_header_length),
             description: MAX_HEADER_LENGTH.short,
             current_value: Box::new(|c| c.max_header_length.to_string()),
         },
         RuleMeta {
+            name: "min_description_length",
+            is_modified: Box::new(|c, d| c.min_description_length != d.min_description_length),
+            description: MIN_DESCRIPTION_LENGTH.short,
+            current_value: Box::new(|c| c.min_description_length.to_string()),
+        },
+        RuleMeta {
+            name: "min_description_words",
+            is_modified: Box::new(|c, d| c.min_description_words != d.min_description_words),
+            description: MIN_DESCRIPTION_WORDS.short,
+            current_value: Box::new(|c| c.min_description_words.to_string()),
+        },
+        RuleMeta {
+            name: "no_vague_description",
+            is_modified: Box::new(|c, d| c.no_vague_description != d.no_vague_description),
+            description: NO_VAGUE_DESCRIPTION.short,
+            current_value: Box::new(|c| c.no_vague_description.to_string()),
+        },
+        RuleMeta {
+            name: "max_body_length",
             is_modified: Box::new(|c, d| c.max_body_length != d.max_body_length),
             description: MAX_BODY_LENGTH.short,
             current_value: Box::new(|c| c.max_body_length.to_string()),
         },
         RuleMeta {
+            name: "body_length_exemptions",
+            is_modified: Box::new(|c, d| c.body_length_exemptions != d.body_length_exemptions),
+            description: BODY_LENGTH_EXEMPTIONS.short,
+            current_value: Box::new(|c| {
+                c.body_length_exemptions
+                    .iter()
+                    .map(|e| e.to_possible_value().unwrap().get_name().to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            }),
+        },
+        RuleMeta {
+            name: "body_required_types",
+            is_modified: Box::new(|c, d| c.body_required_types != d.body_required_types),
+            description: BODY_REQUIRED_TYPES.short,
+            current_value: Box::new(|c| c.body_required_types.join(", ")),
+        },
+        RuleMeta {
+            name: "body_required_header_length",
+            is_modified: Box::new(|c, d| {
+                c.body_required_header_length != d.body_required_header_length
+            }),
+            description: BODY_REQUIRED_HEADER_LENGTH.short,
+            current_value: Box::new(|c| c.body_required_header_length.to_string()),
+        },
+        RuleMeta {
+            name: "body_required_captures",
+            is_modified: Box::new(|c, d| c.body_required_captures != d.body_required_captures),
+            description: BODY_REQUIRED_CAPTURES.short,
+            current_value: Box::new(|c| c.body_required_captures.join(", ")),
+        },
+        RuleMeta {
+            name: "min_body_length",
+            is_modified: Box::new(|c, d| c.min_body_length != d.min_body_length),
+            description: MIN_BODY_LENGTH.short,
+            current_value: Box::new(|c| c.min_body_length.to_string()),
+        },
+        RuleMeta {
+            name: "no_repeated_header",
+            is_modified: Box::new(|c, d| c.no_repeated_header != d.no_repeated_header),
+            description: NO_REPEATED_HEADER.short,
+            current_value: Box::new(|c| c.no_repeated_header.to_string()),
+        },
+        RuleMeta {
+            name: "consistent_bullets",
+            is_modified: Box::new(|c, d| c.consistent_bullets != d.consistent_bullets),
+            description: CONSISTENT_BULLETS.short,
+            current_value: Box::new(|c| c.consistent_bullets.to_string()),
+        },
+        RuleMeta {
+            name: "scopes_allowed",
             is_modified: Box::new(|c, d| c.scopes_allowed != d.scopes_allowed),
             description: SCOPES_ALLOWED.short,
             current_value: Box::new(|c| c.scopes_allowed.join(", ")),
         },
         RuleMeta {
+            name: "types_allowed",
             is_modified: Box::new(|c, d| c.types_allowed != d.types_allowed),
             description: TYPES_ALLOWED.short,
             current_value: Box::new(|c| c.types_allowed.join(", ")),
         },
         RuleMeta {
+            name: "types",
+            is_modified: Box::new(|c, d| c.types != d.types),
+            description: TYPES.short,
+            current_value: Box::new(|c| {
+                format_definitions(
+                    c.types
+                        .iter()
+                        .map(|(name, t)| (name, t.description.as_str(), &t.aliases)),
+                )
+            }),
+        },
+        RuleMeta {
+            name: "scopes",
+            is_modified: Box::new(|c, d| c.scopes != d.scopes),
+            description: SCOPES.short,
+            current_value: Box::new(|c| {
+                format_definitions(
+                    c.scopes
+                        .iter()
+                        .map(|(name, s)| (name, s.description.as_str(), &s.aliases)),
+                )
+            }),
+        },
+        RuleMeta {
+            name: "lowercase_type",
+            is_modified: Box::new(|c, d| c.lowercase_type != d.lowercase_type),
+            description: LOWERCASE_TYPE.short,
+            current_value: Box::new(|c| c.lowercase_type.to_string()),
+        },
+        RuleMeta {
+            name: "scope_required",
+            is_modified: Box::new(|c, d| c.scope_required != d.scope_required),
+            description: SCOPE_REQUIRED.short,
+            current_value: Box::new(|c| c.scope_required.to_string()),
+        },
+        RuleMeta {
+            name: "scope_required_types",
+            is_modified: Box::new(|c, d| c.scope_required_types != d.scope_required_types),
+            description: SCOPE_REQUIRED_TYPES.short,
+            current_value: Box::new(|c| c.scope_required_types.join(", ")),
+        },
+        RuleMeta {
+            name: "scope_case",
+            is_modified: Box::new(|c, d| c.scope_case != d.scope_case),
+            description: SCOPE_CASE.short,
+            current_value: Box::new(|c| {
+                c.scope_case
+                    .to_possible_value()
+                    .unwrap()
+                    .get_name()
+                    .to_string()
+            }),
+        },
+        RuleMeta {
+            name: "no_multiple_scopes",
+            is_modified: Box::new(|c, d| c.no_multiple_scopes != d.no_multiple_scopes),
+            description: NO_MULTIPLE_SCOPES.short,
+            current_value: Box::new(|c| c.no_multiple_scopes.to_string()),
+        },
+        RuleMeta {
+            name: "single_space_after_colon",
+            is_modified: Box::new(|c, d| c.single_space_after_colon != d.single_space_after_colon),
+            description: SINGLE_SPACE_AFTER_COLON.short,
+            current_value: Box::new(|c| c.single_space_after_colon.to_string()),
+        },
+        RuleMeta {
+            name: "breaking_requires_footer",
+            is_modified: Box::new(|c, d| c.breaking_requires_footer != d.breaking_requires_footer),
+            description: BREAKING_REQUIRES_FOOTER.short,
+            current_value: Box::new(|c| c.breaking_requires_footer.to_string()),
+        },
+        RuleMeta {
+            name: "breaking_requires_bang",
+            is_modified: Box::new(|c, d| c.breaking_requires_bang != d.breaking_requires_bang),
+            description: BREAKING_REQUIRES_BANG.short,
+            current_value: Box::new(|c| c.breaking_requires_bang.to_string()),
+        },
+        RuleMeta {
+            name: "min_breaking_description_length",
+            is_modified: Box::new(|c, d| {
+                c.min_breaking_description_length != d.min_breaking_description_length
+            }),
+            description: MIN_BREAKING_DESCRIPTION_LENGTH.short,
+            current_value: Box::new(|c| c.min_breaking_description_length.to_string()),
+        },
+        RuleMeta {
+            name: "breaking_forbidden_types",
+            is_modified: Box::new(|c, d| c.breaking_forbidden_types != d.breaking_forbidden_types),
+            description: BREAKING_FORBIDDEN_TYPES.short,
+            current_value: Box::new(|c| c.breaking_forbidden_types.join(", ")),
+        },
+        RuleMeta {
+            name: "breaking_forbidden_scopes",
+            is_modified: Box::new(|c, d| {
+                c.breaking_forbidden_scopes != d.breaking_forbidden_scopes
+            }),
+            description: BREAKING_FORBIDDEN_SCOPES.short,
+            current_value: Box::new(|c| c.breaking_forbidden_scopes.join(", ")),
+        },
+        RuleMeta {
+            name: "breaking_forbidden_branches",
+            is_modified: Box::new(|c, d| {
+                c.breaking_forbidden_branches != d.breaking_forbidden_branches
+            }),
+            description: BREAKING_FORBIDDEN_BRANCHES.short,
+            current_value: Box::new(|c| c.breaking_forbidden_branches.join(", ")),
+        },
+        RuleMeta {
+            name: "header_pattern",
             is_modified: Box::new(|c, d| c.header_pattern != d.header_pattern),
             description: HEADER_PATTERN.short,
             current_value: Box::new(|c| c.header_pattern.clone()),
         },
         RuleMeta {
+            name: "strip_header_pattern",
             is_modified: Box::new(|c, d| c.strip_header_pattern != d.strip_header_pattern),
             description: STRIP_HEADER_PATTERN.short,
             current_value: Box::new(|c| c.strip_header_pattern.to_string()),
         },
+        RuleMeta {
+            name: "header_format",
+            is_modified: Box::new(|c, d| c.header_format != d.header_format),
+            description: HEADER_FORMAT.short,
+            current_value: Box::new(|c| c.header_format.clone()),
+        },
+        RuleMeta {
+            name: "header_patterns",
+            is_modified: Box::new(|c, d| c.header_patterns != d.header_patterns),
+            description: HEADER_PATTERNS.short,
+            current_value: Box::new(|c| {
+                c.header_patterns
+                    .iter()
+                    .map(|p| match &p.message {
+                        Some(message) => format!("\n#   {} ({message})", p.pattern),
+                        None => format!("\n#   {}", p.pattern),
+                    })
+                    .collect()
+            }),
+        },
     ]
 }
 
+/// Formats type or scope definitions as one comment line each, for the prepare-commit-msg template.
+fn format_definitions<'a>(
+    definitions: impl Iterator<Item = (&'a String, &'a str, &'a Vec<String>)>,
+) -> String {
+    definitions
+        .map(|(name, description, aliases)| {
+            let mut line = format!("\n#   {name}");
+            if !description.is_empty() {
+                line += &format!(": {description}");
+            }
+            if !aliases.is_empty() {
+                line += &format!(" (aliases: {})", aliases.join(", "));
+            }
+            line
+        })
+        .collect()
+}
+
+/// Names of all the rules, enabled or not.
+pub fn rule_names() -> Vec<&'static str> {
+    rules_metadata()
+        .into_iter()
+        .map(|rule_meta| rule_meta.name)
+        .collect()
+}
+
+/// Names of the enabled rules, like 'no_period'.
+pub fn active_rule_names(config: &Config) -> Vec<&'static str> {
+    let default_config = Config::default();
+    rules_metadata()
+        .into_iter()
+        .filter(|rule_meta| (rule_meta.is_modified)(config, &default_config))
+        .map(|rule_meta| rule_meta.name)
+        .collect()
+}
+
 pub fn count_active_rules(config: &Config) -> usize {
     let default_config = Config::default();
     let rules_meta = rules_metadata();
@@ -181,20 +765,111 @@ impl Configurable for Opt {
     fn configure(&self, config: &mut Config) {
         update_field!(config.gitmoji, self.gitmoji);
         update_field!(config.conventional, self.conventional);
+        update_field!(config.convention, self.convention, option);
         update_field!(config.split_lines, self.split_lines);
+        update_field!(config.fix, self.fix);
+        update_field!(config.skip_authors, self.skip_authors, list);
+        update_field!(config.skip_merge_commits, self.skip_merge_commits);
+        update_field!(config.unsuppressible_rules, self.unsuppressible_rules, list);
+        update_field!(config.baseline, self.baseline, option);
         update_field!(config.quiet, self.quiet);
         update_field!(config.display, self.display);
         update_field!(config.format, self.format, option);
+        update_field!(config.color, self.color, option);
         update_field!(config.description_case, self.description_case, option);
         update_field!(config.imperative, self.imperative);
+        update_field!(config.language, self.language, option);
         update_field!(config.whitespace, self.whitespace);
         update_field!(config.no_period, self.no_period);
+        update_field!(config.spelling, self.spelling);
+        update_field!(config.forbidden_words, self.forbidden_words);
+        update_field!(
+            config.forbidden_words_extra,
+            self.forbidden_words_extra,
+            list
+        );
         update_field!(config.max_body_length, self.max_body_length, option);
         update_field!(config.max_header_length, self.max_header_length, option);
+        update_field!(
+            config.min_description_length,
+            self.min_description_length,
+            option
+        );
+        update_field!(
+            config.min_description_words,
+            self.min_description_words,
+            option
+        );
+        update_field!(config.no_vague_description, self.no_vague_description);
+        update_field!(
+            config.body_length_exemptions,
+            self.body_length_exemptions,
+            list
+        );
+        update_field!(config.body_required_types, self.body_required_types, list);
+        update_field!(
+            config.body_required_header_length,
+            self.body_required_header_length,
+            option
+        );
+        update_field!(
+            config.body_required_captures,
+            self.body_required_captures,
+            list
+        );
+        update_field!(config.min_body_length, self.min_body_length, option);
+        update_field!(config.no_repeated_header, self.no_repeated_header);
+        update_field!(config.consistent_bullets, self.consistent_bullets);
         update_field!(config.scopes_allowed, self.scopes_allowed, list);
         update_field!(config.types_allowed, self.types_allowed, list);
+        update_field!(config.lowercase_type, self.lowercase_type);
+        update_field!(config.scope_required, self.scope_required);
+        update_field!(config.scope_required_types, self.scope_required_types, list);
+        update_field!(config.scope_case, self.scope_case, option);
+        update_field!(config.no_multiple_scopes, self.no_multiple_scopes);
+        update_field!(config.scope_delimiters, self.scope_delimiters, option);
+        update_field!(
+            config.scope_hierarchy_separator,
+            self.scope_hierarchy_separator,
+            option
+        );
+        update_field!(
+            config.single_space_after_colon,
+            self.single_space_after_colon
+        );
+        update_field!(
+            config.breaking_requires_footer,
+            self.breaking_requires_footer
+        );
+        update_field!(config.breaking_requires_bang, self.breaking_requires_bang);
+        update_field!(
+            config.min_breaking_description_length,
+            self.min_breaking_description_length,
+            option
+        );
+        update_field!(
+            config.breaking_forbidden_types,
+            self.breaking_forbidden_types,
+            list
+        );
+        update_field!(
+            config.breaking_forbidden_scopes,
+            self.breaking_forbidden_scopes,
+            list
+        );
+        update_field!(
+            config.breaking_forbidden_branches,
+            self.breaking_forbidden_branches,
+            list
+        );
         update_field!(config.header_pattern, self.header_pattern, option);
         update_field!(config.strip_header_pattern, self.strip_header_pattern);
+        update_field!(config.header_format, self.header_format, option);
+        update_field!(
+            config.header_patterns_match,
+            self.header_patterns_match,
+            option
+        );
     }
 }
 
@@ -249,7 +924,7 @@ fn init_commit_msg_hook() -> Result<(), SumiError> {
 
 fn ensure_git_repository(git_dir: &Path) -> Result<(), SumiError> {
     if !git_dir.exists() {
-        return Err(SumiError::GeneralError {
+        return Err(SumiError::GitError {
             details: "No .git directory found. Are you in a Git repository?".to_string(),
         });
     }
@@ -354,6 +1029,119 @@ impl Config {
         Config::default()
     }
 
+    /// The convention used to parse commits: `convention`, or Conventional Commits
+    /// when only the `conventional` rule is enabled.
+    pub fn active_convention(&self) -> Convention {
+        match self.convention {
+            Convention::None if self.conventional => Convention::Conventional,
+            convention => convention,
+        }
+    }
+
+    /// Compiles `header_pattern` and `header_patterns`. Fails if a pattern is invalid.
+    pub fn compile_header_patterns(&mut self) -> Result<(), SumiError> {
+        let header_pattern = HeaderPattern {
+            pattern: self.header_pattern.clone(),
+            message: None,
+        };
+        self.compiled_header_patterns = std::iter::once(&header_pattern)
+            .filter(|p| !p.pattern.is_empty())
+            .chain(&self.header_patterns)
+            .map(|p| {
+                let regex = Regex::new(&p.pattern).map_err(|_| SumiError::InvalidRegexPattern {
+                    pattern: p.pattern.clone(),
+                })?;
+                Ok(CompiledHeaderPattern {
+                    pattern: p.pattern.clone(),
+                    regex,
+                    message: p.message.clone(),
+                })
+            })
+            .collect::<Result<_, SumiError>>()?;
+        Ok(())
+    }
+
+    /// The compiled `header_pattern`, if set. It comes first in `compiled_header_patterns`.
+    pub fn header_pattern_regex(&self) -> Option<&Regex> {
+        if self.header_pattern.is_empty() {
+            return None;
+        }
+        self.compiled_header_patterns.first().map(|p| &p.regex)
+    }
+
+    /// Compiles `header_format`. Fails if the template is invalid.
+    pub fn compile_header_format(&mut self) -> Result<(), SumiError> {
+        self.compiled_header_format = match self.header_format.as_str() {
+            "" => None,
+            template => Some(HeaderFormat::compile(template)?),
+        };
+        Ok(())
+    }
+
+    /// Compiles the built-in and extra forbidden words, if the rule is enabled.
+    pub fn compile_forbidden_words(&mut self) -> Result<(), SumiError> {
+        self.compiled_forbidden_words = if self.forbidden_words {
+            Some(ForbiddenWords::compile(&self.forbidden_words_extra)?)
+        } else {
+            None
+        };
+        Ok(())
+    }
+
+    /// Applies each `[[author_overrides]]` table to this config.
+    /// Fails if an override has an invalid value.
+    pub fn compile_author_overrides(&mut self) -> Result<(), SumiError> {
+        let mut base = toml::Table::try_from(&*self)?;
+        base.remove("author_overrides");
+        self.compiled_author_overrides = self
+            .author_overrides
+            .iter()
+            .map(|author_override| {
+                let mut table = base.clone();
+                table.extend(author_override.options.clone());
+                let mut config: Config = toml::Value::Table(table).try_into()?;
+                adjust_config(&mut config);
+                config.compile_header_patterns()?;
+                config.compile_header_format()?;
+                config.compile_forbidden_words()?;
+                Ok(CompiledAuthorOverride {
+                    authors: author_override.authors.clone(),
+                    config,
+                })
+            })
+            .collect::<Result<_, SumiError>>()?;
+        Ok(())
+    }
+
+    /// Resolves the checked out branch for `breaking_forbidden_branches`,
+    /// shared with the author overrides so that git runs at most once.
+    pub fn resolve_current_branch(&mut self) {
+        let needs_branch = std::iter::once(&*self)
+            .chain(self.compiled_author_overrides.iter().map(|o| &o.config))
+            .any(|config| !config.breaking_forbidden_branches.is_empty());
+        if !needs_branch {
+            return;
+        }
+        self.current_branch = get_current_branch();
+        for author_override in &mut self.compiled_author_overrides {
+            author_override.config.current_branch = self.current_branch.clone();
+        }
+    }
+
+    /// Whether the commit is left out of range linting by `skip_authors` or `skip_merge_commits`.
+    pub fn skips_commit(&self, metadata: &CommitMetadata) -> bool {
+        (self.skip_merge_commits && metadata.is_merge())
+            || matches_author(&self.skip_authors, metadata)
+    }
+
+    /// The configuration for a commit: that of the first `[[author_overrides]]` matching its author, if any.
+    pub fn for_commit(&self, metadata: &CommitMetadata) -> &Config {
+        self.compiled_author_overrides
+            .iter()
+            .find(|author_override| matches_author(&author_override.authors, metadata))
+            .map_or(self, |author_override| &author_override.config)
+    }
+
     pub fn update_from<T: Configurable>(&mut self, args: &T) {
         args.configure(self);
     }
@@ -366,19 +1154,80 @@ impl Config {
             ("quiet", (&QUIET, false)),
             ("display", (&DISPLAY, false)),
             ("format", (&FORMAT, false)),
+            ("color", (&COLOR, false)),
             ("split_lines", (&SPLIT_LINES, false)),
+            ("fix", (&FIX, false)),
+            ("skip_authors", (&SKIP_AUTHORS, false)),
+            ("skip_merge_commits", (&SKIP_MERGE_COMMITS, false)),
+            ("unsuppressible_rules", (&UNSUPPRESSIBLE_RULES, false)),
+            ("baseline", (&BASELINE, false)),
             ("gitmoji", (&GITMOJI, true)),
             ("description_case", (&DESCRIPTION_CASE, true)),
             ("imperative", (&IMPERATIVE, true)),
+            ("language", (&LANGUAGE, false)),
             ("no_period", (&NO_PERIOD, true)),
+            ("spelling", (&SPELLING, true)),
+            ("forbidden_words", (&FORBIDDEN_WORDS, true)),
+            ("forbidden_words_extra", (&FORBIDDEN_WORDS_EXTRA, true)),
             ("whitespace", (&WHITESPACE, true)),
             ("max_header_length", (&MAX_HEADER_LENGTH, true)),
+            ("min_description_length", (&MIN_DESCRIPTION_LENGTH, true)),
+            ("min_description_words", (&MIN_DESCRIPTION_WORDS, true)),
+            ("no_vague_description", (&NO_VAGUE_DESCRIPTION, true)),
             ("max_body_length", (&MAX_BODY_LENGTH, true)),
+            ("body_length_exemptions", (&BODY_LENGTH_EXEMPTIONS, true)),
+            ("body_required_types", (&BODY_REQUIRED_TYPES, true)),
+            (
+                "body_required_header_length",
+                (&BODY_REQUIRED_HEADER_LENGTH, true),
+            ),
+            ("body_required_captures", (&BODY_REQUIRED_CAPTURES, true)),
+            ("min_body_length", (&MIN_BODY_LENGTH, true)),
+            ("no_repeated_header", (&NO_REPEATED_HEADER, true)),
+            ("consistent_bullets", (&CONSISTENT_BULLETS, true)),
             ("conventional", (&CONVENTIONAL, true)),
+            ("convention", (&CONVENTION, true)),
             ("scopes_allowed", (&SCOPES_ALLOWED, true)),
             ("types_allowed", (&TYPES_ALLOWED, true)),
+            ("lowercase_type", (&LOWERCASE_TYPE, true)),
+            ("scope_required", (&SCOPE_REQUIRED, true)),
+            ("scope_required_types", (&SCOPE_REQUIRED_TYPES, true)),
+            ("scope_case", (&SCOPE_CASE, true)),
+            ("no_multiple_scopes", (&NO_MULTIPLE_SCOPES, true)),
+            ("scope_delimiters", (&SCOPE_DELIMITERS, false)),
+            (
+                "scope_hierarchy_separator",
+                (&SCOPE_HIERARCHY_SEPARATOR, false),
+            ),
+            (
+                "single_space_after_colon",
+                (&SINGLE_SPACE_AFTER_COLON, true),
+            ),
+            (
+                "breaking_requires_footer",
+                (&BREAKING_REQUIRES_FOOTER, true),
+            ),
+            ("breaking_requires_bang", (&BREAKING_REQUIRES_BANG, true)),
+            (
+                "min_breaking_description_length",
+                (&MIN_BREAKING_DESCRIPTION_LENGTH, true),
+            ),
+            (
+                "breaking_forbidden_types",
+                (&BREAKING_FORBIDDEN_TYPES, true),
+            ),
+            (
+                "breaking_forbidden_scopes",
+                (&BREAKING_FORBIDDEN_SCOPES, true),
+            ),
+            (
+                "breaking_forbidden_branches",
+                (&BREAKING_FORBIDDEN_BRANCHES, true),
+            ),
             ("header_pattern", (&HEADER_PATTERN, true)),
             ("strip_header_pattern", (&STRIP_HEADER_PATTERN, true)),
+            ("header_format", (&HEADER_FORMAT, true)),
+            ("header_patterns_match", (&HEADER_PATTERNS_MATCH, false)),
         ];
 
         let config_comments: HashMap<&str, String> = config_keys_and_rules
@@ -445,9 +1294,22 @@ pub fn assemble_config(args: &Opt) -> Result<Config, SumiError> {
     };
     config.update_from(args);
     adjust_config(&mut config);
+    config.compile_header_patterns()?;
+    config.compile_header_format()?;
+    config.compile_forbidden_words()?;
+    config.compile_author_overrides()?;
+    config.resolve_current_branch();
     Ok(config)
 }
 
+/// Whether any pattern matches the author's name or email.
+fn matches_author(patterns: &[String], metadata: &CommitMetadata) -> bool {
+    split_and_trim_list(patterns).iter().any(|pattern| {
+        matches_wildcard(pattern, &metadata.author_name)
+            || matches_wildcard(pattern, &metadata.author_email)
+    })
+}
+
 /// Finds and returns the path of the configuration file.
 ///
 /// The function searches for `sumi.toml` or `.sumi.toml`:
@@ -470,6 +1332,12 @@ fn find_config_file() -> Result<Option<PathBuf>, SumiError> {
     Ok(None)
 }
 
+/// Finds the project dictionary (`.sumi-words.txt`) in the current directory or its parents.
+pub fn find_project_dictionary() -> Option<PathBuf> {
+    let current_dir = fs::canonicalize(std::env::current_dir().ok()?).ok()?;
+    find_config_in_ancestors(current_dir, &[PROJECT_DICTIONARY_FILE_NAME])
+}
+
 fn find_config_in_ancestors(starting_dir: PathBuf, filenames: &[&str]) -> Option<PathBuf> {
     let mut current = starting_dir;
     loop {
@@ -509,7 +1377,11 @@ fn find_in_dir(directory: PathBuf, filenames: &[&str]) -> Option<PathBuf> {
 /// Returns the parsed configuration or an error if the file cannot be found or parsed.
 fn load_config<P: AsRef<Path>>(file_path: P) -> Result<Config, SumiError> {
     validate_file_path(&file_path)?;
-    let file_contents_as_string = fs::read_to_string(&file_path)?;
+    let file_contents_as_string =
+        fs::read_to_string(&file_path).map_err(|e| SumiError::ConfigFileUnreadable {
+            path: file_path.as_ref().to_string_lossy().into_owned(),
+            detail: e.to_string(),
+        })?;
     let parsed_configuration = toml::from_str(&file_contents_as_string)?;
     Ok(parsed_configuration)
 }
@@ -529,8 +1401,36 @@ fn validate_file_path<P: AsRef<Path>>(path: P) -> Result<(), SumiError> {
     Ok(())
 }
 
+fn uses_strict_conventional_rules(config: &Config) -> bool {
+    config.lowercase_type
+        || config.scope_required
+        || !config.scope_required_types.is_empty()
+        || config.scope_case != ScopeCase::Any
+        || config.no_multiple_scopes
+        || config.single_space_after_colon
+}
+
+fn uses_breaking_change_rules(config: &Config) -> bool {
+    config.breaking_requires_footer
+        || config.breaking_requires_bang
+        || config.min_breaking_description_length > 0
+        || !config.breaking_forbidden_types.is_empty()
+        || !config.breaking_forbidden_scopes.is_empty()
+        || !config.breaking_forbidden_branches.is_empty()
+}
+
 fn adjust_config(config: &mut Config) {
-    if !config.types_allowed.is_empty() || !config.scopes_allowed.is_empty() {
+    if !config.types_allowed.is_empty()
+        || !config.scopes_allowed.is_empty()
+        || !config.body_required_types.is_empty()
+        || !config.types.is_empty()
+        || !config.scopes.is_empty()
+        || uses_strict_conventional_rules(config)
+        || uses_breaking_change_rules(config)
+    {
         config.conventional = true;
     }
+    if !config.forbidden_words_extra.is_empty() {
+        config.forbidden_words = true;
+    }
 }
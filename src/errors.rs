@@ -22,12 +22,42 @@ pub enum SumiError {
         commit_or_commits: String,
     },
 
+    #[error("{commits_with_errors} out of {total_commits} {commit_or_commits} failed linting. See the `errors` field of each commit")]
+    CommitRangeDocumentErrors {
+        commits_with_errors: usize,
+        total_commits: usize,
+        commit_or_commits: String,
+    },
+
+    #[error("Found {count} linting {error_or_errors}")]
+    LintingErrors {
+        count: usize,
+        error_or_errors: String,
+    },
+
     #[error("Failed to parse as a conventional commit: '{reason}'")]
-    FailedConventionalParse { reason: String },
+    FailedConventionalParse {
+        reason: String,
+        line_number: usize,
+        column: usize,
+    },
+
+    #[error("Failed to parse with the {convention} convention: '{reason}'")]
+    FailedConventionParse { convention: String, reason: String },
+
+    #[error("Header must follow the format '{format}'")]
+    HeaderConventionMismatch { format: String },
 
     #[error("Separate header from body with a blank line")]
     SeparateHeaderFromBody,
 
+    #[error("Whitespace {issue_or_issues} detected: {issues}")]
+    WhitespaceIssues {
+        line_number: usize,
+        issue_or_issues: String,
+        issues: String,
+    },
+
     #[error("Header must not end with a period")]
     HeaderEndsWithPeriod,
 
@@ -44,6 +74,104 @@ pub enum SumiError {
         max_length: usize,
     },
 
+    #[error("Unknown word '{word}' on line {line_number}{}", format_suggestions(.suggestions))]
+    UnknownWord {
+        word: String,
+        line_number: usize,
+        suggestions: Vec<String>,
+    },
+
+    #[error("Forbidden word '{word}' on line {line_number}, column {column}{}", format_replacement(.replacement))]
+    ForbiddenWord {
+        word: String,
+        line_number: usize,
+        column: usize,
+        replacement: Option<String>,
+    },
+
+    #[error("Body is required for '{commit_type}' commits")]
+    BodyRequiredForType { commit_type: String },
+
+    #[error("Body is required when the header pattern captures '{name}' ('{value}')")]
+    BodyRequiredForCapture { name: String, value: String },
+
+    #[error("Body is required when the header is longer than {max_length} characters (header has {header_length})")]
+    BodyRequiredForLongHeader {
+        header_length: usize,
+        max_length: usize,
+    },
+
+    #[error("Body is too short ({body_length} < {min_length})")]
+    BodyTooShort {
+        body_length: usize,
+        min_length: usize,
+    },
+
+    #[error("Body on line {line_number} only repeats the header")]
+    BodyRepeatsHeader { line_number: usize },
+
+    #[error("Inconsistent bullet marker '{marker}' on line {line_number}. Use '{expected}' like line {first_line_number}")]
+    InconsistentBulletMarker {
+        marker: String,
+        expected: String,
+        line_number: usize,
+        first_line_number: usize,
+    },
+
+    #[error("Description is too short ({description_length} < {min_length})")]
+    DescriptionTooShort {
+        description_length: usize,
+        min_length: usize,
+    },
+
+    #[error("Description has too few words ({word_count} < {min_words})")]
+    DescriptionTooFewWords { word_count: usize, min_words: usize },
+
+    #[error("Description '{description}' is too vague. Describe what changed and why")]
+    VagueDescription { description: String },
+
+    #[error("Commit type '{type_found}' must be lowercase")]
+    CommitTypeNotLowercase { type_found: String },
+
+    #[error("Commit scope is required")]
+    MissingScope,
+
+    #[error("Commit scope must not be empty")]
+    EmptyScope { column: usize },
+
+    #[error("Commit scope is required for type '{commit_type}'")]
+    MissingScopeForType { commit_type: String },
+
+    #[error("Commit scope '{scope}' must be {case}")]
+    InvalidScopeCase { scope: String, case: String },
+
+    #[error("Only one commit scope is allowed, found '{scope}'")]
+    MultipleScopes { scope: String },
+
+    #[error("Expected exactly one space after the colon in the header, found {spaces}")]
+    SpacesAfterColon { spaces: usize },
+
+    #[error("Breaking changes marked with '!' must include a 'BREAKING CHANGE:' footer")]
+    BreakingChangeMissingFooter,
+
+    #[error("Breaking changes with a 'BREAKING CHANGE:' footer must add '!' before the colon in the header")]
+    BreakingChangeMissingBang,
+
+    #[error("Breaking change description is too short ({description_length} < {min_length})")]
+    BreakingDescriptionTooShort {
+        description_length: usize,
+        min_length: usize,
+    },
+
+    #[error("Breaking changes are not allowed for type '{commit_type}'")]
+    BreakingChangeForbiddenForType { commit_type: String },
+
+    #[error("Breaking changes are not allowed for scope '{scope}'")]
+    BreakingChangeForbiddenForScope { scope: String },
+
+    #[error("Breaking changes are not allowed on branch '{branch}' (matches '{pattern}')")]
+    BreakingChangeForbiddenOnBranch { branch: String, pattern: String },
+
     #[error("Header must contain exactly 1 emoji, found {found}")]
     IncorrectEmojiCount { found: usize },
 
@@ -53,16 +181,21 @@ pub enum SumiError {
     #[error("Description starts with a non-imperative verb: '{verb}'. Use an imperative verb like 'fix', instead of 'fixes' or 'fixing'")]
     NonImperativeVerb { verb: String },
 
-    #[error("Invalid commit type '{type_found}'. Allowed types are: [{allowed_types}]")]
+    #[error("Description uses a non-imperative verb: '{verb}'. Try '{suggestion}'")]
+    NonImperativeVerbWithSuggestion { verb: String, suggestion: String },
+
+    #[error("Invalid commit type '{type_found}'. Allowed types are: [{allowed_types}]{}", format_suggestions(.suggestions))]
     InvalidCommitType {
         type_found: String,
         allowed_types: String,
+        suggestions: Vec<String>,
     },
 
-    #[error("Invalid commit scope '{scope_found}'. Allowed scopes are: [{allowed_scopes}]")]
+    #[error("Invalid commit scope '{scope_found}'. Allowed scopes are: [{allowed_scopes}]{}", format_suggestions(.suggestions))]
     InvalidCommitScope {
         scope_found: String,
         allowed_scopes: String,
+        suggestions: Vec<String>,
     },
 
     #[error(
@@ -76,12 +209,41 @@ pub enum SumiError {
     #[error("Header must not be empty")]
     EmptyCommitHeader,
 
+    #[error("Description must not be empty")]
+    EmptyDescription,
+
     #[error("Header regex pattern '{pattern}' is invalid")]
     InvalidRegexPattern { pattern: String },
 
     #[error("Header does not match the required pattern: '{pattern}'")]
     HeaderPatternMismatch { pattern: String },
 
+    #[error("{message}")]
+    HeaderPatternMessage { message: String },
+
+    #[error("Header does not match any of the allowed patterns: [{patterns}]")]
+    NoHeaderPatternMatched { patterns: String },
+
+    #[error("Forbidden words are invalid: {reason}")]
+    InvalidForbiddenWords { reason: String },
+
+    #[error("Header format '{format}' is invalid: {reason}")]
+    InvalidHeaderFormat { format: String, reason: String },
+
+    #[error("Header must follow the format '{format}': expected {expected} at column {column}, found {found}")]
+    HeaderFormatMismatch {
+        format: String,
+        expected: String,
+        column: usize,
+        found: String,
+    },
+
+    #[error("Unknown rule '{rule}' in the Sumi-Disable trailer")]
+    UnknownSuppressedRule { rule: String, line_number: usize },
+
+    #[error("Rule '{rule}' can't be disabled with a Sumi-Disable trailer")]
+    SuppressionForbidden { rule: String, line_number: usize },
+
     #[error(transparent)]
     InvalidConventionalCommit(#[from] git_conventional::Error),
 
@@ -91,6 +253,9 @@ pub enum SumiError {
     #[error("Failed to commit changes. {0}")]
     ErrorWhileCommitting(String),
 
+    #[error("{details}")]
+    GitError { details: String },
+
     #[error(transparent)]
     InputOutputError(#[from] std::io::Error),
 
@@ -107,6 +272,188 @@ pub enum SumiError {
 
     #[error("The specified path '{path}' is a directory, not a configuration file")]
     PathIsDirectory { path: String },
+
+    #[error("Could not read the configuration file '{path}': {detail}")]
+    ConfigFileUnreadable { path: String, detail: String },
+
+    #[error("Could not load the baseline file '{path}': {detail}")]
+    InvalidBaseline { path: String, detail: String },
+
+    #[error("{details}")]
+    InvalidArguments { details: String },
+}
+
+impl SumiError {
+    /// Name of the rule that reports this error, like 'no_period'.
+    pub fn rule(&self) -> Option<&'static str> {
+        use SumiError::*;
+        let rule = match self {
+            FailedConventionalParse { .. } | EmptyScope { .. } | InvalidConventionalCommit(_) => {
+                "conventional"
+            }
+            FailedConventionParse { .. } | HeaderConventionMismatch { .. } => "convention",
+            WhitespaceIssues { .. } => "whitespace",
+            HeaderEndsWithPeriod => "no_period",
+            DescriptionNotLowercase { .. } | DescriptionNotTitleCase { .. } => "description_case",
+            LineTooLong { line_number: 1, .. } => "max_header_length",
+            LineTooLong { .. } => "max_body_length",
+            UnknownWord { .. } => "spelling",
+            ForbiddenWord { .. } => "forbidden_words",
+            BodyRequiredForType { .. } => "body_required_types",
+            BodyRequiredForCapture { .. } => "body_required_captures",
+            BodyRequiredForLongHeader { .. } => "body_required_header_length",
+            BodyTooShort { .. } => "min_body_length",
+            BodyRepeatsHeader { .. } => "no_repeated_header",
+            InconsistentBulletMarker { .. } => "consistent_bullets",
+            DescriptionTooShort { .. } => "min_description_length",
+            DescriptionTooFewWords { .. } => "min_description_words",
+            VagueDescription { .. } => "no_vague_description",
+            CommitTypeNotLowercase { .. } => "lowercase_type",
+            MissingScope => "scope_required",
+            MissingScopeForType { .. } => "scope_required_types",
+            InvalidScopeCase { .. } => "scope_case",
+            MultipleScopes { .. } => "no_multiple_scopes",
+            SpacesAfterColon { .. } => "single_space_after_colon",
+            BreakingChangeMissingFooter => "breaking_requires_footer",
+            BreakingChangeMissingBang => "breaking_requires_bang",
+            BreakingDescriptionTooShort { .. } => "min_breaking_description_length",
+            BreakingChangeForbiddenForType { .. } => "breaking_forbidden_types",
+            BreakingChangeForbiddenForScope { .. } => "breaking_forbidden_scopes",
+            BreakingChangeForbiddenOnBranch { .. } => "breaking_forbidden_branches",
+            IncorrectEmojiCount { .. } | InvalidEmoji { .. } => "gitmoji",
+            NonImperativeVerb { .. } | NonImperativeVerbWithSuggestion { .. } => "imperative",
+            InvalidCommitType { .. } => "types_allowed",
+            InvalidCommitScope { .. } => "scopes_allowed",
+            HeaderPatternMismatch { .. } => "header_pattern",
+            HeaderPatternMessage { .. } | NoHeaderPatternMatched { .. } => "header_patterns",
+            HeaderFormatMismatch { .. } => "header_format",
+            _ => return None,
+        };
+        Some(rule)
+    }
+
+    /// Whether the error comes from an invalid configuration rather than from the commit.
+    /// Reported alongside rule violations, it still ends the run as a configuration error.
+    pub fn is_config_error(&self) -> bool {
+        use SumiError::*;
+        matches!(
+            self,
+            NoRulesEnabled
+                | IncompatibleRules { .. }
+                | InvalidRegexPattern { .. }
+                | InvalidHeaderFormat { .. }
+                | InvalidForbiddenWords { .. }
+                | ConfigLoadError(_)
+                | ConfigFileNotFound { .. }
+                | PathIsDirectory { .. }
+                | ConfigFileUnreadable { .. }
+                | InvalidBaseline { .. }
+                | InvalidArguments { .. }
+        )
+    }
+
+    /// How to fix the error, for errors whose message doesn't already say.
+    pub fn help(&self) -> Option<String> {
+        use SumiError::*;
+        let help = match self {
+            HeaderEndsWithPeriod => "remove the period at the end of the header".to_string(),
+            LineTooLong {
+                line_number: 1,
+                max_length,
+                ..
+            } => format!("shorten the header to {max_length} characters"),
+            LineTooLong { max_length, .. } => format!("wrap the line at {max_length} columns"),
+            WhitespaceIssues { .. } => "remove the extra whitespace".to_string(),
+            SeparateHeaderFromBody => "add a blank line after the header".to_string(),
+            CommitTypeNotLowercase { type_found } => {
+                format!("use '{}'", type_found.to_lowercase())
+            }
+            MissingScope | MissingScopeForType { .. } => {
+                "add a scope after the type, like 'feat(api): …'".to_string()
+            }
+            MultipleScopes { .. } => "keep a single scope".to_string(),
+            EmptyScope { .. } => "add a scope inside the parentheses, or remove them".to_string(),
+            SpacesAfterColon { .. } => "use a single space after the colon".to_string(),
+            BreakingChangeMissingFooter => {
+                "add a 'BREAKING CHANGE: <description>' footer".to_string()
+            }
+            BreakingChangeMissingBang => "add '!' before the colon, like 'feat!: …'".to_string(),
+            BodyRequiredForType { .. }
+            | BodyRequiredForCapture { .. }
+            | BodyRequiredForLongHeader { .. }
+            | BodyTooShort { .. } => {
+                "explain what changed and why in the body, after a blank line".to_string()
+            }
+            BodyRepeatsHeader { .. } => {
+                "explain why the change was made instead of repeating the header".to_string()
+            }
+            SuppressionForbidden { rule, .. } => {
+                format!("fix the violation and remove '{rule}' from the trailer")
+            }
+            _ => return None,
+        };
+        Some(help)
+    }
+}
+
+/// Outcome of a run, telling rule violations apart from other failures.
+/// Each kind of failure exits with its own code.
+#[derive(Debug)]
+pub enum Outcome {
+    /// Exit code 0.
+    Success,
+    /// The commit message breaks at least one rule. Exit code 1.
+    LintFailure(SumiError),
+    /// The configuration or options are invalid. Exit code 2.
+    ConfigError(SumiError),
+    /// A git command failed. Exit code 3.
+    GitError(SumiError),
+    /// Reading input, writing output or serializing failed. Exit code 4.
+    IoError(SumiError),
+}
+
+impl Outcome {
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Outcome::Success => 0,
+            Outcome::LintFailure(_) => 1,
+            Outcome::ConfigError(_) => 2,
+            Outcome::GitError(_) => 3,
+            Outcome::IoError(_) => 4,
+        }
+    }
+
+    pub fn error(&self) -> Option<&SumiError> {
+        match self {
+            Outcome::Success => None,
+            Outcome::LintFailure(err)
+            | Outcome::ConfigError(err)
+            | Outcome::GitError(err)
+            | Outcome::IoError(err) => Some(err),
+        }
+    }
+}
+
+impl From<Result<(), SumiError>> for Outcome {
+    fn from(result: Result<(), SumiError>) -> Self {
+        use SumiError::*;
+        let Err(err) = result else {
+            return Outcome::Success;
+        };
+        if err.is_config_error() {
+            return Outcome::ConfigError(err);
+        }
+        match err {
+            GitError { .. } | ErrorWhileCommitting(_) => Outcome::GitError(err),
+            GeneralError { .. }
+            | CommitFileError { .. }
+            | InputOutputError(_)
+            | SerializationError { .. }
+            | ConfigDumpError(_) => Outcome::IoError(err),
+            // Every other error is a rule violation.
+            _ => Outcome::LintFailure(err),
+        }
+    }
 }
 
 pub fn pluralize<'a>(count: usize, singular: &'a str, plural: &'a str) -> &'a str {
@@ -116,3 +463,18 @@ pub fn pluralize<'a>(count: usize, singular: &'a str, plural: &'a str) -> &'a st
         plural
     }
 }
+
+fn format_suggestions(suggestions: &[String]) -> String {
+    if suggestions.is_empty() {
+        return String::new();
+    }
+    let quoted: Vec<String> = suggestions.iter().map(|s| format!("'{s}'")).collect();
+    format!(". Did you mean {}?", quoted.join(", "))
+}
+
+fn format_replacement(replacement: &Option<String>) -> String {
+    match replacement {
+        Some(replacement) => format!(". Use '{replacement}' instead"),
+        None => String::new(),
+    }
+}
@@ -1,4 +1,5 @@
 use crate::errors::SumiError;
+use serde::Serialize;
 use std::sync::OnceLock;
 
 static COMMENT_CHAR: OnceLock<String> = OnceLock::new();
@@ -18,7 +19,8 @@ pub fn get_git_commentchar() -> Result<String, SumiError> {
         .arg("config")
         .arg("--get")
         .arg("core.commentchar")
-        .output()?;
+        .output()
+        .map_err(spawn_error)?;
 
     if output.status.success() {
         Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
@@ -28,7 +30,7 @@ pub fn get_git_commentchar() -> Result<String, SumiError> {
             // commentchar isn't set, so fallback to #
             Ok("#".to_string())
         } else {
-            Err(SumiError::GeneralError {
+            Err(SumiError::GitError {
                 details: format!("Failed to get git comment character: {}", stderr.trim()),
             })
         }
@@ -55,18 +57,115 @@ pub fn execute_git_commit(commit_message: &str) -> Result<std::process::Output,
     std::process::Command::new("git")
         .args(["commit", "-m", commit_message])
         .output()
-        .map_err(SumiError::from)
+        .map_err(spawn_error)
+}
+
+/// Git couldn't be run at all, e.g. because it isn't installed.
+fn spawn_error(err: std::io::Error) -> SumiError {
+    SumiError::GitError {
+        details: format!("Failed to run git: {err}"),
+    }
+}
+
+/// Returns the short name of the checked out branch, or `None` on a detached HEAD
+/// or outside a repository.
+pub fn get_current_branch() -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(["symbolic-ref", "--quiet", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Some(branch).filter(|branch| !branch.is_empty())
+}
+
+/// Returns the tags reachable from `rev`.
+pub fn get_tags_merged_into(rev: &str) -> Result<Vec<String>, SumiError> {
+    let output = std::process::Command::new("git")
+        .args(["tag", "--list", "--merged", rev])
+        .output()
+        .map_err(spawn_error)?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(SumiError::GitError {
+            details: format!("Failed to get tags merged into '{rev}': {}", stderr.trim()),
+        });
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect())
+}
+
+/// Author, committer and parents of a commit. Dates are in strict ISO 8601 format.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct CommitMetadata {
+    pub author_name: String,
+    pub author_email: String,
+    pub author_date: String,
+    pub committer_name: String,
+    pub committer_email: String,
+    pub committer_date: String,
+    /// Number of parents: 0 for a root commit, 2 or more for a merge commit.
+    pub parents: usize,
+}
+
+impl CommitMetadata {
+    pub fn is_merge(&self) -> bool {
+        self.parents > 1
+    }
+}
+
+/// A commit read from the repository.
+#[derive(Debug, Clone)]
+pub struct GitCommit {
+    pub sha: String,
+    pub message: String,
+    pub metadata: CommitMetadata,
 }
 
-pub fn get_commits_in_range(from: &str, to: &str) -> Result<Vec<(String, String)>, SumiError> {
-    let range = format!("{from}..{to}");
+impl GitCommit {
+    pub fn short_sha(&self) -> &str {
+        &self.sha[..7.min(self.sha.len())]
+    }
+}
+
+pub fn get_commits_in_range(from: &str, to: &str) -> Result<Vec<GitCommit>, SumiError> {
+    get_commits(&format!("{from}..{to}"))
+}
+
+/// Returns the commits reachable from `to`, or in `from..to` when `from` is set.
+pub fn get_commits_up_to(from: Option<&str>, to: &str) -> Result<Vec<GitCommit>, SumiError> {
+    match from {
+        Some(from) => get_commits_in_range(from, to),
+        None => get_commits(to),
+    }
+}
+
+/// SHA, author, committer and parents, separated by the unit separator, then the message.
+const COMMIT_FORMAT: &str = "%H%x1f%an%x1f%ae%x1f%aI%x1f%cn%x1f%ce%x1f%cI%x1f%P%n%B";
+
+/// Returns each commit in a revision range, oldest first.
+fn get_commits(range: &str) -> Result<Vec<GitCommit>, SumiError> {
     let output = std::process::Command::new("git")
-        .args(["log", "--reverse", "-z", "--format=%H%n%B", &range])
-        .output()?;
+        .args([
+            "log",
+            "--reverse",
+            "-z",
+            &format!("--format={COMMIT_FORMAT}"),
+            range,
+        ])
+        .output()
+        .map_err(spawn_error)?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(SumiError::GeneralError {
+        return Err(SumiError::GitError {
             details: format!(
                 "Failed to get commits in range '{range}': {}",
                 stderr.trim()
@@ -76,14 +175,30 @@ pub fn get_commits_in_range(from: &str, to: &str) -> Result<Vec<(String, String)
 
     let stdout = String::from_utf8_lossy(&output.stdout);
     let mut commits = Vec::new();
-    // With `-z`, each record is "<sha>\n<message>", NUL-terminated.
+    // With `-z`, each record is "<fields>\n<message>", NUL-terminated.
     // Empty messages are kept so they fail linting instead of being skipped.
     for record in stdout.split('\0') {
         if record.is_empty() {
             continue;
         }
-        let (sha, message) = record.split_once('\n').unwrap_or((record, ""));
-        commits.push((sha.to_string(), message.trim().to_string()));
+        let (fields, message) = record.split_once('\n').unwrap_or((record, ""));
+        let mut fields = fields.split('\x1f').map(str::to_string);
+        let mut next = || fields.next().unwrap_or_default();
+        let sha = next();
+        let metadata = CommitMetadata {
+            author_name: next(),
+            author_email: next(),
+            author_date: next(),
+            committer_name: next(),
+            committer_email: next(),
+            committer_date: next(),
+            parents: next().split_whitespace().count(),
+        };
+        commits.push(GitCommit {
+            sha,
+            message: message.trim().to_string(),
+            metadata,
+        });
     }
 
     Ok(commits)
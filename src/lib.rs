@@ -1,26 +1,62 @@
 mod args;
+mod baseline;
+mod changelog;
 mod config;
 mod errors;
 mod git;
 pub mod lint;
 mod parser;
+mod stats;
+mod version;
 
-use crate::errors::SumiError;
-use args::Opt;
+use crate::errors::{pluralize, SumiError};
+use args::{Command, Opt, RangeArgs};
+use baseline::{Baseline, DEFAULT_BASELINE_PATH};
+use changelog::generate_changelog;
 use clap::{CommandFactory, Parser};
 use config::{
     assemble_config, count_active_rules, generate_commit_msg_hook_content, init_config, Config,
 };
 use env_logger::Builder;
+pub use errors::Outcome;
 use git::{execute_git_commit, remove_verbose_output};
-use lint::{run_lint, run_lint_on_commit_range, run_lint_on_each_line};
+use lint::{fix_header, run_lint, run_lint_on_commit_range, run_lint_on_each_line};
 use log::{error, info, LevelFilter};
 use parser::ParsedCommit;
+use std::ffi::OsString;
 use std::io::{self, Read, Write};
 
-pub fn run() -> Result<(), SumiError> {
-    let args = Opt::parse();
+/// Runs git-sumi with the command line arguments.
+/// The outcome tells rule violations apart from configuration, git and I/O errors.
+pub fn run() -> Outcome {
+    run_from(std::env::args_os())
+}
+
+/// Runs git-sumi with the given arguments, the first being the binary name.
+/// Invalid arguments are a configuration error.
+pub fn run_from<I, T>(args: I) -> Outcome
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone,
+{
+    match Opt::try_parse_from(args) {
+        Ok(args) => Outcome::from(run_with_args(args)),
+        // Clap reports --help and --version as errors too.
+        Err(err) if !err.use_stderr() => {
+            let _ = err.print();
+            Outcome::Success
+        }
+        Err(err) => Outcome::ConfigError(SumiError::InvalidArguments {
+            details: err
+                .to_string()
+                .trim_start_matches("error: ")
+                .trim_end()
+                .to_string(),
+        }),
+    }
+}
 
+fn run_with_args(args: Opt) -> Result<(), SumiError> {
     if let Some(init_option) = args.init {
         init_config(init_option)?;
         return Ok(());
@@ -34,6 +70,10 @@ pub fn run() -> Result<(), SumiError> {
     let config = assemble_config(&args)?;
     init_logger_from_config(&config);
 
+    if let Some(command) = &args.command {
+        return run_command(command, &config);
+    }
+
     if args.prepare_commit_message {
         generate_commit_msg_hook_content(&config)?;
         return Ok(());
@@ -47,15 +87,20 @@ pub fn run() -> Result<(), SumiError> {
     // Commit range mode.
     if let (Some(from), Some(to)) = (&args.from, &args.to) {
         let commits = git::get_commits_in_range(from, to)?;
-        if commits.is_empty() {
+        if commits.is_empty() && !(config.display && config.format.is_document()) {
             info!("No commits found in range {from}..{to}");
             return Ok(());
         }
-        let result = run_lint_on_commit_range(commits, &config);
+        let baseline = Baseline::from_config(&config)?;
+        let result = run_lint_on_commit_range(commits, &config, baseline.as_ref());
         return result.map(|_| ());
     }
 
-    let commit_message = get_commit_from_arg_or_stdin(args.commit_message, args.commit_file)?;
+    let mut commit_message =
+        get_commit_from_arg_or_stdin(args.commit_message, args.commit_file.clone())?;
+    if config.fix {
+        commit_message = apply_fixes(commit_message, args.commit_file.as_deref(), &config)?;
+    }
 
     let lint_result = if config.split_lines {
         run_lint_on_each_line(&commit_message, &config, None)
@@ -72,6 +117,90 @@ pub fn run() -> Result<(), SumiError> {
     Ok(())
 }
 
+fn run_command(command: &Command, config: &Config) -> Result<(), SumiError> {
+    match command {
+        Command::Changelog(changelog_args) => {
+            let range = changelog_args.range.range();
+            let commits = commits_in_range(&changelog_args.range)?;
+            if commits.is_empty() {
+                info!("No commits found in range {range}");
+                return Ok(());
+            }
+            let template = match &changelog_args.template {
+                Some(path) => Some(read_template(path)?),
+                None => None,
+            };
+            print!(
+                "{}",
+                generate_changelog(&commits, config, &range, template.as_deref())
+            );
+            Ok(())
+        }
+        Command::NextVersion(next_version_args) => {
+            let latest_tag = version::latest_version_tag(&git::get_tags_merged_into("HEAD")?);
+            let from = latest_tag.as_ref().map(|tag| tag.name.as_str());
+            let commits = git::get_commits_up_to(from, "HEAD")?;
+            let next_version = version::next_version(&commits, config, latest_tag.as_ref());
+            if next_version_args.json {
+                let serialized = serde_json::to_string_pretty(&next_version).map_err(|err| {
+                    SumiError::SerializationError {
+                        format: "JSON".to_string(),
+                        detail: err.to_string(),
+                    }
+                })?;
+                println!("{serialized}");
+            } else {
+                println!("{}", next_version.next_version);
+            }
+            Ok(())
+        }
+        Command::Stats(stats_args) => {
+            if count_active_rules(config) == 0 {
+                return Err(SumiError::NoRulesEnabled);
+            }
+            let commits = commits_in_range(&stats_args.range)?;
+            if commits.is_empty() {
+                info!("No commits found in range {}", stats_args.range.range());
+                return Ok(());
+            }
+            let stats = stats::collect_stats(&commits, config);
+            println!("{}", stats::render_stats(&stats, stats_args.format)?);
+            Ok(())
+        }
+        Command::Baseline(baseline_args) => {
+            if count_active_rules(config) == 0 {
+                return Err(SumiError::NoRulesEnabled);
+            }
+            let commits = commits_in_range(&baseline_args.range)?;
+            let baseline = Baseline::collect(&commits, config);
+            let path = match &baseline_args.output {
+                Some(output) => output.as_str(),
+                None if !config.baseline.is_empty() => config.baseline.as_str(),
+                None => DEFAULT_BASELINE_PATH,
+            };
+            baseline.write(path)?;
+            let violations = baseline.violation_count();
+            let commits_with_violations = baseline.commits.len();
+            info!(
+                "📋 Wrote {violations} {} in {commits_with_violations} {} to '{path}'",
+                pluralize(violations, "violation", "violations"),
+                pluralize(commits_with_violations, "commit", "commits")
+            );
+            Ok(())
+        }
+    }
+}
+
+fn commits_in_range(range: &RangeArgs) -> Result<Vec<git::GitCommit>, SumiError> {
+    git::get_commits_up_to(range.from.as_deref(), &range.to)
+}
+
+fn read_template(path: &str) -> Result<String, SumiError> {
+    std::fs::read_to_string(path).map_err(|e| SumiError::GeneralError {
+        details: format!("Could not read template from '{path}': {e}"),
+    })
+}
+
 fn init_logger_from_config(config: &Config) {
     Builder::new()
         .format(|buf, record| {
@@ -90,7 +219,9 @@ fn init_logger_from_config(config: &Config) {
             },
         )
         .target(env_logger::Target::Stdout)
-        .init();
+        // The logger is already set when git-sumi runs more than once in a process.
+        .try_init()
+        .ok();
 }
 
 fn get_commit_from_arg_or_stdin(
@@ -106,6 +237,24 @@ fn get_commit_from_arg_or_stdin(
     remove_verbose_output(&msg)
 }
 
+/// Fixes the commit header, updating the commit file if the message was read from one.
+fn apply_fixes(
+    commit_message: String,
+    commit_file: Option<&str>,
+    config: &Config,
+) -> Result<String, SumiError> {
+    let header = commit_message.lines().next().unwrap_or_default();
+    let Some(fixed_header) = fix_header(header, config) else {
+        return Ok(commit_message);
+    };
+    info!("🔧 Fixed header: \"{fixed_header}\"");
+    if let Some(path) = commit_file {
+        let content = std::fs::read_to_string(path)?;
+        std::fs::write(path, content.replacen(header, &fixed_header, 1))?;
+    }
+    Ok(commit_message.replacen(header, &fixed_header, 1))
+}
+
 fn get_commit_from_file(path: &str) -> Result<String, SumiError> {
     std::fs::read_to_string(path)
         .map(|content| content.trim().to_string())
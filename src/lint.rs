@@ -1,34 +1,94 @@
 pub mod constants;
+mod diagnostic;
 mod display;
+mod fix;
+pub(crate) mod forbidden_words;
+mod spelling;
+mod suggestions;
+mod suppression;
 
-use crate::config::{self, count_active_rules, DescriptionCase};
+pub use fix::fix_header;
+
+use crate::baseline::{accept_baselined, Baseline};
+use crate::config::{
+    self, count_active_rules, BodyLengthExemption, CompiledHeaderPattern, Convention,
+    DescriptionCase, HeaderPatternsMatch, Language, ScopeCase,
+};
 use crate::errors;
 use crate::errors::{pluralize, SumiError};
-use crate::parser::{handle_parsing, ParsedCommit};
+use crate::git::{CommitMetadata, GitCommit};
+use crate::parser::{handle_parsing, ParsedCommit, EMOJI_REGEX};
 
 use config::Config;
 use constants::gitmoji::{STRING_EMOJIS, UNICODE_EMOJIS};
 use constants::non_imperative_verbs::NON_IMPERATIVE_VERBS;
-use display::display_parsed_commit;
+use constants::non_imperative_verbs_de::{NON_IMPERATIVE_VERBS_DE, PAST_PARTICIPLES_DE};
+use constants::non_imperative_verbs_es::NON_IMPERATIVE_VERBS_ES;
+use constants::vague_descriptions::VAGUE_DESCRIPTIONS;
+use diagnostic::render_diagnostic;
+use display::{display_commit_range, display_parsed_commit, LintStatus, RangeEntry};
 use log::{error, info};
 use regex::Regex;
+use spelling::validate_spelling;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::sync::LazyLock;
+use suppression::{apply_suppressions, Suppression};
+use unicode_width::UnicodeWidthStr;
 
+/// Lints each commit with the configuration for its author.
+/// Commits left out by `skip_authors` or `skip_merge_commits` don't count,
+/// and violations in the baseline are accepted.
 pub fn run_lint_on_commit_range(
-    commits: Vec<(String, String)>,
+    commits: Vec<GitCommit>,
     config: &Config,
+    baseline: Option<&Baseline>,
 ) -> Result<Vec<ParsedCommit>, SumiError> {
-    let total_commits = commits.len();
+    if config.display && config.format.is_document() {
+        return lint_commit_range_into_document(&commits, config, baseline);
+    }
+    let mut total_commits = 0;
     let mut parsed_commits = Vec::new();
     let mut errors = Vec::new();
+    let mut suppression_report = Vec::new();
 
-    for (sha, message) in &commits {
-        let short_sha = &sha[..7.min(sha.len())];
-        let prefix = format!("[{short_sha}] ");
+    for commit in &commits {
+        let prefix = format!("[{}] ", commit.short_sha());
+        let metadata = &commit.metadata;
+        if config.skips_commit(metadata) {
+            info!(
+                "{prefix}⏭️ Skipped {} by {}",
+                if metadata.is_merge() {
+                    "merge commit"
+                } else {
+                    "commit"
+                },
+                metadata.author_name
+            );
+            continue;
+        }
+        total_commits += 1;
+        let config = config.for_commit(metadata);
+        let accepted = baseline.and_then(|baseline| baseline.accepted_rules(&commit.sha));
+        let mut suppressions = Vec::new();
         let result = if config.split_lines {
-            run_lint_on_each_line(message, config, Some(&prefix))
+            lint_each_line(
+                &commit.message,
+                config,
+                Some(&prefix),
+                Some(metadata),
+                accepted,
+                &mut suppressions,
+            )
         } else {
-            run_lint(message, config, Some(&prefix)).map(|pc| vec![pc])
+            lint_message(
+                &commit.message,
+                config,
+                Some(&prefix),
+                Some(metadata),
+                accepted,
+                &mut suppressions,
+            )
+            .map(|pc| vec![pc])
         };
         match result {
             Ok(pcs) => parsed_commits.extend(pcs),
@@ -37,12 +97,101 @@ pub fn run_lint_on_commit_range(
                 errors.push(err);
             }
         }
+        suppression_report.extend(suppressions.into_iter().map(|s| (prefix.clone(), s)));
     }
 
-    if errors.is_empty() {
+    report_suppressions(&suppression_report);
+    commit_range_result(parsed_commits, errors.len(), total_commits)
+}
+
+/// Lists the violations disabled by `Sumi-Disable` trailers in a range, for auditing.
+fn report_suppressions(report: &[(String, Suppression)]) {
+    if report.is_empty() {
+        return;
+    }
+    info!(
+        "🔕 {} {} disabled by Sumi-Disable trailers:",
+        report.len(),
+        pluralize(report.len(), "violation", "violations")
+    );
+    for (prefix, suppression) in report {
+        info!("{prefix}{}: {}", suppression.rule, suppression.error);
+    }
+}
+
+/// Lints the commits without logging, then displays them as a single document
+/// listing each commit's SHA, lint status and errors.
+fn lint_commit_range_into_document(
+    commits: &[GitCommit],
+    config: &Config,
+    baseline: Option<&Baseline>,
+) -> Result<Vec<ParsedCommit>, SumiError> {
+    let mut total_commits = 0;
+    let mut commits_with_errors = 0;
+    let mut entries = Vec::new();
+
+    for commit in commits {
+        if config.skips_commit(&commit.metadata) {
+            entries.push(RangeEntry::skipped(commit));
+            continue;
+        }
+        total_commits += 1;
+        let config = config.for_commit(&commit.metadata);
+        let accepted = baseline.and_then(|baseline| baseline.accepted_rules(&commit.sha));
+        let first_entry = entries.len();
+        for message in messages_to_lint(commit, config) {
+            entries.push(RangeEntry::linted(
+                commit,
+                lint_silently(message, config),
+                accepted,
+            ));
+        }
+        if entries[first_entry..]
+            .iter()
+            .any(|entry| entry.status == LintStatus::Failed)
+        {
+            commits_with_errors += 1;
+        }
+    }
+
+    display_commit_range(&entries, &config.format)?;
+    let parsed_commits = entries
+        .into_iter()
+        .filter(|entry| entry.status == LintStatus::Passed)
+        .filter_map(|entry| entry.commit)
+        .collect();
+    if commits_with_errors == 0 {
+        return Ok(parsed_commits);
+    }
+    // The errors are in the document rather than logged, so point there.
+    Err(SumiError::CommitRangeDocumentErrors {
+        commits_with_errors,
+        total_commits,
+        commit_or_commits: pluralize(total_commits, "commit", "commits").to_string(),
+    })
+}
+
+/// The messages linted for a commit: each non-empty line with `split_lines`, or the whole message.
+pub(crate) fn messages_to_lint<'a>(commit: &'a GitCommit, config: &Config) -> Vec<&'a str> {
+    if config.split_lines {
+        commit
+            .message
+            .lines()
+            .filter(|line| !line.is_empty())
+            .collect()
+    } else {
+        vec![&commit.message]
+    }
+}
+
+fn commit_range_result(
+    parsed_commits: Vec<ParsedCommit>,
+    commits_with_errors: usize,
+    total_commits: usize,
+) -> Result<Vec<ParsedCommit>, SumiError> {
+    if commits_with_errors == 0 {
         Ok(parsed_commits)
     } else {
-        let commits_with_errors = errors.len();
         let commit_plural = pluralize(total_commits, "commit", "commits");
         Err(SumiError::CommitRangeErrors {
             commits_with_errors,
@@ -56,6 +205,24 @@ pub fn run_lint_on_each_line(
     commit_message: &str,
     config: &Config,
     log_prefix: Option<&str>,
+) -> Result<Vec<ParsedCommit>, SumiError> {
+    lint_each_line(
+        commit_message,
+        config,
+        log_prefix,
+        None,
+        None,
+        &mut Vec::new(),
+    )
+}
+
+fn lint_each_line(
+    commit_message: &str,
+    config: &Config,
+    log_prefix: Option<&str>,
+    metadata: Option<&CommitMetadata>,
+    accepted: Option<&BTreeSet<String>>,
+    suppressions: &mut Vec<Suppression>,
 ) -> Result<Vec<ParsedCommit>, SumiError> {
     let non_empty_lines = commit_message.lines().filter(|line| !line.is_empty());
     let prefix = log_prefix.unwrap_or("");
@@ -63,7 +230,7 @@ pub fn run_lint_on_each_line(
     let mut errors = Vec::new();
 
     for line in non_empty_lines.clone() {
-        match run_lint(line, config, log_prefix) {
+        match lint_message(line, config, log_prefix, metadata, accepted, suppressions) {
             Ok(parsed_commit) => parsed_commits.push(parsed_commit),
             Err(error) => {
                 error!("{prefix}{error}");
@@ -93,19 +260,73 @@ pub fn run_lint(
     raw_commit: &str,
     config: &Config,
     log_prefix: Option<&str>,
+) -> Result<ParsedCommit, SumiError> {
+    lint_message(raw_commit, config, log_prefix, None, None, &mut Vec::new())
+}
+
+/// Like `run_lint`, attaching the metadata of commits read from the repository,
+/// accepting the commit's baseline rules
+/// and collecting the violations disabled by `Sumi-Disable` trailers.
+fn lint_message(
+    raw_commit: &str,
+    config: &Config,
+    log_prefix: Option<&str>,
+    metadata: Option<&CommitMetadata>,
+    accepted: Option<&BTreeSet<String>>,
+    suppressions: &mut Vec<Suppression>,
 ) -> Result<ParsedCommit, SumiError> {
     let prefix = log_prefix.unwrap_or("");
     let commit = preprocess_commit_message(raw_commit);
     info!("{prefix}💬 Input: \"{commit}\"");
-    let mut non_fatal_errors: Vec<SumiError> = Vec::new();
-    let parsed_commit = handle_parsing(&commit, config, &mut non_fatal_errors)?;
-    let errors = validate_commit(&commit, &parsed_commit, config);
-    non_fatal_errors.extend(errors);
-    if non_fatal_errors.is_empty() {
+    let CheckedCommit {
+        mut parsed_commit,
+        mut errors,
+        suppressions: disabled,
+    } = check_commit(&commit, config);
+    for err in accept_baselined(&mut errors, accepted) {
+        info!("{prefix}📋 Accepted by the baseline: {err}");
+    }
+    parsed_commit.metadata = metadata.cloned();
+    for suppression in &disabled {
+        info!(
+            "{prefix}🔕 Disabled {}: {}",
+            suppression.rule, suppression.error
+        );
+    }
+    suppressions.extend(disabled);
+    if errors.is_empty() {
         handle_success(&parsed_commit, config, prefix)?;
         return Ok(parsed_commit);
     }
-    handle_failure(&non_fatal_errors, prefix)
+    handle_failure(&errors, &commit, config, prefix)
+}
+
+/// A commit message checked against the rules.
+pub(crate) struct CheckedCommit {
+    pub parsed_commit: ParsedCommit,
+    pub errors: Vec<SumiError>,
+    /// Violations disabled by `Sumi-Disable` trailers.
+    pub suppressions: Vec<Suppression>,
+}
+
+/// Lints the commit message without logging.
+pub(crate) fn lint_silently(raw_commit: &str, config: &Config) -> CheckedCommit {
+    check_commit(&preprocess_commit_message(raw_commit), config)
+}
+
+fn check_commit(commit: &String, config: &Config) -> CheckedCommit {
+    let mut errors: Vec<SumiError> = Vec::new();
+    let parsed_commit = handle_parsing(commit, config, &mut errors);
+    errors.extend(validate_commit(commit, &parsed_commit, config));
+    // Rules checking a partial parse can repeat a parse error, like an empty header.
+    let mut seen = HashSet::new();
+    errors.retain(|err| seen.insert(err.to_string()));
+    let suppressions = apply_suppressions(commit, &mut errors, config);
+    CheckedCommit {
+        parsed_commit,
+        errors,
+        suppressions,
+    }
 }
 
 fn preprocess_commit_message(commit: &str) -> String {
@@ -126,6 +347,14 @@ fn validate_commit(
 ) -> Vec<SumiError> {
     let mut errors = validate_whitespace_and_length(commit.to_string(), config);
     errors.extend(validate_parsed_commit(parsed_commit, config));
+    if config.spelling {
+        let validation_description = get_validation_description(parsed_commit, config);
+        errors.extend(validate_spelling(commit, &validation_description));
+    }
+    if let Some(forbidden_words) = &config.compiled_forbidden_words {
+        errors.extend(forbidden_words.find(commit));
+    }
+    errors.extend(validate_body_structure(commit, parsed_commit, config));
     errors
 }
 
@@ -134,11 +363,11 @@ fn validate_whitespace_and_length(commit: String, config: &Config) -> Vec<SumiEr
     let mut lines = commit.lines();
     let header_line = lines.next().unwrap_or("");
     let validation_header = if should_strip_header_pattern(config) {
-        strip_header_pattern_from_line(header_line, &config.header_pattern)
+        strip_header_pattern_from_line(header_line, config)
     } else {
         header_line.to_string()
     };
-    if let Err(err) = validate_whitespace(&validation_header, config) {
+    if let Err(err) = validate_whitespace(&validation_header, 1, config) {
         errors.push(err);
     }
     if let Err(actual_length) = validate_line_length(header_line, config.max_header_length) {
@@ -156,11 +385,10 @@ fn should_strip_header_pattern(config: &Config) -> bool {
     config.strip_header_pattern && !config.header_pattern.is_empty()
 }
 
-fn strip_header_pattern_from_line(line: &str, pattern: &str) -> String {
-    if let Ok(regex) = Regex::new(pattern) {
-        regex.replace(line, "").to_string()
-    } else {
-        line.to_string()
+fn strip_header_pattern_from_line(line: &str, config: &Config) -> String {
+    match config.header_pattern_regex() {
+        Some(regex) => regex.replace(line, "").to_string(),
+        None => line.to_string(),
     }
 }
 
@@ -175,17 +403,34 @@ fn validate_line_length(line: &str, max_length: usize) -> Result<(), usize> {
     Ok(())
 }
 
+// Body lines are measured in terminal columns: East Asian wide characters and emoji count as 2.
+fn validate_line_width(line: &str, max_width: usize) -> Result<(), usize> {
+    if max_width == 0 {
+        return Ok(());
+    }
+    let actual_width = line.width();
+    if actual_width > max_width {
+        return Err(actual_width);
+    }
+    Ok(())
+}
+
 fn validate_body_lines(lines: std::str::Lines, config: &Config) -> Vec<SumiError> {
     let mut errors = Vec::new();
-    for (line_number, line) in lines.enumerate() {
+    let lines: Vec<&str> = lines.collect();
+    let code_lines = find_code_lines(&lines);
+    for (line_number, line) in lines.iter().enumerate() {
         if line_number == 0 && !line.is_empty() {
             errors.push(SumiError::SeparateHeaderFromBody);
             continue;
         }
-        if let Err(err) = validate_whitespace(line, config) {
+        if let Err(err) = validate_whitespace(line, line_number + 2, config) {
             errors.push(err);
         }
-        if let Err(actual_length) = validate_line_length(line, config.max_body_length) {
+        if is_exempt_from_length(line, code_lines[line_number], config) {
+            continue;
+        }
+        if let Err(actual_length) = validate_line_width(line, config.max_body_length) {
             errors.push(SumiError::LineTooLong {
                 line_number: line_number + 2,
                 line_length: actual_length,
@@ -196,49 +441,74 @@ fn validate_body_lines(lines: std::str::Lines, config: &Config) -> Vec<SumiError
     errors
 }
 
-fn validate_whitespace(line: &str, config: &Config) -> Result<(), SumiError> {
+fn is_exempt_from_length(line: &str, is_code: bool, config: &Config) -> bool {
+    config
+        .body_length_exemptions
+        .iter()
+        .any(|exemption| match exemption {
+            BodyLengthExemption::Url => URL_LINE_REGEX.is_match(line),
+            BodyLengthExemption::Code => is_code,
+            BodyLengthExemption::Quote => line.trim_start().starts_with('>'),
+            BodyLengthExemption::Trailer => TRAILER_REGEX.is_match(line),
+        })
+}
+
+/// Marks the lines that belong to fenced (```) or indented code blocks, fences included.
+fn find_code_lines(lines: &[&str]) -> Vec<bool> {
+    let mut in_fenced_block = false;
+    lines
+        .iter()
+        .map(|line| {
+            if line.trim_start().starts_with("```") {
+                in_fenced_block = !in_fenced_block;
+                return true;
+            }
+            in_fenced_block
+                || (!line.trim().is_empty() && (line.starts_with("    ") || line.starts_with('\t')))
+        })
+        .collect()
+}
+
+static URL_LINE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    // Regex: a single URL, optionally preceded by a list marker and/or a reference label like "[1]:".
+    Regex::new(r"^\s*(?:[-*+]\s+)?(?:\[\w+\]:?\s+)?<?[a-zA-Z][\w+.-]*://\S+?>?\s*$").unwrap()
+});
+
+static TRAILER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    // Regex: git trailers and Conventional Commits footers ("Token: value" or "Token #value").
+    Regex::new(r"^([\w-]+|BREAKING CHANGE)(: | #)").unwrap()
+});
+
+fn validate_whitespace(line: &str, line_number: usize, config: &Config) -> Result<(), SumiError> {
     if !config.whitespace {
         return Ok(());
     }
 
-    let mut issues = Vec::new();
-    let highlighted_line = WHITESPACE_REGEX.replace_all(line, |caps: &regex::Captures| {
-        let len = caps[0].len();
-        let start = caps.get(0).unwrap().start();
-        let end = caps.get(0).unwrap().end();
-
-        if start == 0 {
-            issues.push("Leading space".to_owned());
-        } else if end == line.len() {
-            issues.push("Trailing space".to_owned());
-        } else {
-            issues.push(format!("{len} adjacent spaces"));
-        }
-
-        "🟥️".repeat(len)
-    });
+    let issues: Vec<String> = WHITESPACE_REGEX
+        .find_iter(line)
+        .map(|found| {
+            if found.start() == 0 {
+                "Leading space".to_owned()
+            } else if found.end() == line.len() {
+                "Trailing space".to_owned()
+            } else {
+                format!("{} adjacent spaces", found.len())
+            }
+        })
+        .collect();
 
     if !issues.is_empty() {
-        let issue_count = issues.len();
-        let issues_list = issues
-            .iter()
-            .map(|issue| format!("  - {issue}: \"{highlighted_line}\""))
-            .collect::<Vec<String>>()
-            .join("\n");
-
-        return Err(SumiError::GeneralError {
-            details: format!(
-                "Whitespace {} detected:\n{}",
-                pluralize(issue_count, "issue", "issues"),
-                issues_list
-            ),
+        return Err(SumiError::WhitespaceIssues {
+            line_number,
+            issue_or_issues: pluralize(issues.len(), "issue", "issues").to_string(),
+            issues: issues.join(", "),
         });
     }
 
     Ok(())
 }
 
-static WHITESPACE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+pub(crate) static WHITESPACE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     // This regex has three capturing groups:
     // - ^\s+ captures leading spaces.
     // - \s+$ captures trailing spaces.
@@ -246,13 +516,126 @@ static WHITESPACE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"(^\s+|\s+$|\s{2,})").unwrap()
 });
 
+fn validate_body_structure(
+    commit: &str,
+    parsed_commit: &ParsedCommit,
+    config: &Config,
+) -> Vec<SumiError> {
+    let mut errors = Vec::new();
+    let body = parsed_commit
+        .body
+        .as_deref()
+        .map(str::trim)
+        .filter(|body| !body.is_empty());
+
+    match body {
+        None => errors.extend(validate_body_presence(parsed_commit, config)),
+        Some(body) => {
+            let body_length = body.chars().count();
+            if config.min_body_length > 0 && body_length < config.min_body_length {
+                errors.push(SumiError::BodyTooShort {
+                    body_length,
+                    min_length: config.min_body_length,
+                });
+            }
+            if config.no_repeated_header && is_repeated_header(body, parsed_commit) {
+                let line_number = commit
+                    .lines()
+                    .skip(1)
+                    .position(|line| !line.trim().is_empty())
+                    .map_or(2, |index| index + 2);
+                errors.push(SumiError::BodyRepeatsHeader { line_number });
+            }
+        }
+    }
+
+    if config.consistent_bullets {
+        errors.extend(validate_bullet_markers(commit));
+    }
+    errors
+}
+
+fn validate_body_presence(parsed_commit: &ParsedCommit, config: &Config) -> Vec<SumiError> {
+    let mut errors = Vec::new();
+    if let Some(commit_type) = &parsed_commit.commit_type {
+        if split_and_trim_list(&config.body_required_types).contains(commit_type) {
+            errors.push(SumiError::BodyRequiredForType {
+                commit_type: commit_type.clone(),
+            });
+        }
+    }
+    for name in split_and_trim_list(&config.body_required_captures) {
+        if let Some(value) = parsed_commit
+            .captures
+            .as_ref()
+            .and_then(|captures| captures.get(&name))
+        {
+            errors.push(SumiError::BodyRequiredForCapture {
+                value: value.clone(),
+                name,
+            });
+        }
+    }
+    let header_length = parsed_commit.header.chars().count();
+    if config.body_required_header_length > 0 && header_length > config.body_required_header_length
+    {
+        errors.push(SumiError::BodyRequiredForLongHeader {
+            header_length,
+            max_length: config.body_required_header_length,
+        });
+    }
+    errors
+}
+
+fn is_repeated_header(body: &str, parsed_commit: &ParsedCommit) -> bool {
+    let normalised_body = normalise_for_comparison(body);
+    normalised_body == normalise_for_comparison(&parsed_commit.description)
+        || normalised_body == normalise_for_comparison(&parsed_commit.header)
+}
+
+// Lowercase words only, so that case, punctuation and spacing don't hide a repeated header.
+fn normalise_for_comparison(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Checks that bullets at the same indentation level use the same marker.
+fn validate_bullet_markers(commit: &str) -> Vec<SumiError> {
+    let mut errors = Vec::new();
+    // Indentation → (marker, line number where it was first used).
+    let mut markers: HashMap<usize, (&str, usize)> = HashMap::new();
+    for (index, line) in commit.lines().enumerate().skip(1) {
+        let Some(caps) = BULLET_REGEX.captures(line) else {
+            continue;
+        };
+        let indentation = caps[1].chars().count();
+        let marker = caps.get(2).unwrap().as_str();
+        let line_number = index + 1;
+        let (expected, first_line_number) =
+            *markers.entry(indentation).or_insert((marker, line_number));
+        if marker != expected {
+            errors.push(SumiError::InconsistentBulletMarker {
+                marker: marker.to_string(),
+                expected: expected.to_string(),
+                line_number,
+                first_line_number,
+            });
+        }
+    }
+    errors
+}
+
+static BULLET_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    // Regex: indentation, followed by an unordered list marker and a space.
+    Regex::new(r"^(\s*)([-*+•])\s+\S").unwrap()
+});
+
 fn validate_parsed_commit(parsed_commit: &ParsedCommit, config: &Config) -> Vec<SumiError> {
     let mut errors: Vec<SumiError> = Vec::new();
-    let validation_description = if should_strip_header_pattern(config) {
-        strip_header_pattern_from_line(&parsed_commit.description, &config.header_pattern)
-    } else {
-        parsed_commit.description.clone()
-    };
+    let validation_description = get_validation_description(parsed_commit, config);
 
     if config.gitmoji {
         if let Err(err) = validate_gitmoji(&parsed_commit.gitmoji) {
@@ -264,8 +647,13 @@ fn validate_parsed_commit(parsed_commit: &ParsedCommit, config: &Config) -> Vec<
         errors.push(err);
     }
 
+    errors.extend(validate_description_content(
+        &validation_description,
+        config,
+    ));
+
     if config.imperative {
-        if let Err(err) = is_imperative(&validation_description) {
+        if let Err(err) = is_imperative(&validation_description, &config.language) {
             errors.push(err);
         }
     }
@@ -276,21 +664,25 @@ fn validate_parsed_commit(parsed_commit: &ParsedCommit, config: &Config) -> Vec<
         }
     }
 
-    if config.conventional {
-        if let Err(err) = validate_commit_type_and_scope(parsed_commit, config) {
-            errors.push(err);
-        }
+    if config.active_convention() != Convention::None {
+        errors.extend(validate_commit_type_and_scope(parsed_commit, config));
+        errors.extend(validate_conventional_format(parsed_commit, config));
+        errors.extend(validate_breaking_change(parsed_commit, config));
     }
 
-    if !config.header_pattern.is_empty() {
-        if let Err(err) = validate_header_pattern(&parsed_commit.header, &config.header_pattern) {
-            errors.push(err);
-        }
-    }
+    errors.extend(validate_header_patterns(&parsed_commit.header, config));
 
     errors
 }
 
+fn get_validation_description(parsed_commit: &ParsedCommit, config: &Config) -> String {
+    if should_strip_header_pattern(config) {
+        strip_header_pattern_from_line(&parsed_commit.description, config)
+    } else {
+        parsed_commit.description.clone()
+    }
+}
+
 /// Validates that the commit title contains exactly one gitmoji.
 /// Returns the normalised gitmoji if it is valid, or an error message if it is not.
 fn validate_gitmoji(emojis: &Option<Vec<String>>) -> Result<(), SumiError> {
@@ -356,16 +748,249 @@ fn validate_upper_case(description: &str) -> Result<(), SumiError> {
     }
 }
 
+/// Validates the length, word count and vagueness of the description, without emoji.
+fn validate_description_content(description: &str, config: &Config) -> Vec<SumiError> {
+    let mut errors = Vec::new();
+    let description_sans_emoji = remove_emoji(description);
+    let words: Vec<&str> = description_sans_emoji.split_whitespace().collect();
+    let description = words.join(" ");
+
+    let description_length = description.chars().count();
+    if config.min_description_length > 0 && description_length < config.min_description_length {
+        errors.push(SumiError::DescriptionTooShort {
+            description_length,
+            min_length: config.min_description_length,
+        });
+    }
+
+    if config.min_description_words > 0 && words.len() < config.min_description_words {
+        errors.push(SumiError::DescriptionTooFewWords {
+            word_count: words.len(),
+            min_words: config.min_description_words,
+        });
+    }
+
+    if config.no_vague_description {
+        let normalised = description
+            .trim_end_matches(|c: char| c.is_ascii_punctuation())
+            .to_lowercase();
+        if VAGUE_DESCRIPTIONS.contains(normalised.as_str()) {
+            errors.push(SumiError::VagueDescription { description });
+        }
+    }
+
+    errors
+}
+
+fn remove_emoji(text: &str) -> String {
+    EMOJI_REGEX.replace_all(text, "").replace('\u{fe0f}', "")
+}
+
+/// Applies the breaking change policies. Only commits parsed as Conventional Commits are checked.
+fn validate_breaking_change(parsed_commit: &ParsedCommit, config: &Config) -> Vec<SumiError> {
+    let mut errors = Vec::new();
+    if !parsed_commit.is_breaking.unwrap_or(false) {
+        return errors;
+    }
+
+    let has_bang = CONVENTIONAL_PREFIX_REGEX
+        .captures(remove_emoji(&parsed_commit.header).trim_start())
+        .is_some_and(|caps| caps.name("bang").is_some());
+    let has_footer = parsed_commit.footers.iter().flatten().any(|footer| {
+        footer.starts_with("BREAKING CHANGE") || footer.starts_with("BREAKING-CHANGE")
+    });
+    if config.breaking_requires_footer && has_bang && !has_footer {
+        errors.push(SumiError::BreakingChangeMissingFooter);
+    }
+    if config.breaking_requires_bang && has_footer && !has_bang {
+        errors.push(SumiError::BreakingChangeMissingBang);
+    }
+
+    let description_length = parsed_commit
+        .breaking_description
+        .as_deref()
+        .unwrap_or_default()
+        .trim()
+        .chars()
+        .count();
+    if config.min_breaking_description_length > 0
+        && description_length < config.min_breaking_description_length
+    {
+        errors.push(SumiError::BreakingDescriptionTooShort {
+            description_length,
+            min_length: config.min_breaking_description_length,
+        });
+    }
+
+    if let Some(commit_type) = &parsed_commit.commit_type {
+        if split_and_trim_list(&config.breaking_forbidden_types).contains(commit_type) {
+            errors.push(SumiError::BreakingChangeForbiddenForType {
+                commit_type: commit_type.clone(),
+            });
+        }
+    }
+    let forbidden_scopes = split_and_trim_list(&config.breaking_forbidden_scopes);
+    for scope in parsed_commit.scopes.iter().flatten() {
+        let is_forbidden = forbidden_scopes.contains(scope)
+            || scope_components(scope, config)
+                .iter()
+                .any(|component| forbidden_scopes.iter().any(|s| s == component));
+        if is_forbidden {
+            errors.push(SumiError::BreakingChangeForbiddenForScope {
+                scope: scope.clone(),
+            });
+        }
+    }
+
+    let forbidden_branches = split_and_trim_list(&config.breaking_forbidden_branches);
+    if let Some(branch) = &config.current_branch {
+        if let Some(pattern) = forbidden_branches
+            .into_iter()
+            .find(|pattern| matches_wildcard(pattern, branch))
+        {
+            errors.push(SumiError::BreakingChangeForbiddenOnBranch {
+                branch: branch.clone(),
+                pattern,
+            });
+        }
+    }
+
+    errors
+}
+
+static CONVENTIONAL_PREFIX_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    // Regex: type, optional (scope), optional "!", colon and the whitespace after it.
+    Regex::new(r"^[^\s(:!]+(?:\([^)]*\))?(?P<bang>!)?:(?P<spaces>[ \t]*)").unwrap()
+});
+
+/// Applies the strictness rules on the type, scope and separator of Conventional Commits.
+fn validate_conventional_format(parsed_commit: &ParsedCommit, config: &Config) -> Vec<SumiError> {
+    let mut errors = Vec::new();
+
+    if let Some(commit_type) = &parsed_commit.commit_type {
+        if config.lowercase_type && commit_type.to_lowercase() != *commit_type {
+            errors.push(SumiError::CommitTypeNotLowercase {
+                type_found: commit_type.clone(),
+            });
+        }
+        if parsed_commit.scope.is_none() {
+            if config.scope_required {
+                errors.push(SumiError::MissingScope);
+            } else if split_and_trim_list(&config.scope_required_types).contains(commit_type) {
+                errors.push(SumiError::MissingScopeForType {
+                    commit_type: commit_type.clone(),
+                });
+            }
+        }
+    }
+
+    if let (Some(scope), Some(scopes)) = (&parsed_commit.scope, &parsed_commit.scopes) {
+        if config.no_multiple_scopes && scopes.len() > 1 {
+            errors.push(SumiError::MultipleScopes {
+                scope: scope.clone(),
+            });
+        }
+        for component in scopes
+            .iter()
+            .flat_map(|scope| scope_components(scope, config))
+        {
+            if let Some(case) = scope_case_mismatch(component, &config.scope_case) {
+                errors.push(SumiError::InvalidScopeCase {
+                    scope: component.to_string(),
+                    case: case.to_string(),
+                });
+            }
+        }
+    }
+
+    if config.single_space_after_colon {
+        let header = remove_emoji(&parsed_commit.header);
+        if let Some(caps) = CONVENTIONAL_PREFIX_REGEX.captures(header.trim_start()) {
+            let spaces = caps["spaces"].chars().count();
+            if spaces != 1 {
+                errors.push(SumiError::SpacesAfterColon { spaces });
+            }
+        }
+    }
+
+    errors
+}
+
+/// Splits a hierarchical scope like "api/auth" into its components.
+/// Without a `scope_hierarchy_separator`, the scope is its only component.
+fn scope_components<'a>(scope: &'a str, config: &Config) -> Vec<&'a str> {
+    if config.scope_hierarchy_separator.is_empty() {
+        return vec![scope];
+    }
+    scope
+        .split(config.scope_hierarchy_separator.as_str())
+        .map(str::trim)
+        .collect()
+}
+
+/// Returns the name of the expected case if the scope doesn't follow it.
+fn scope_case_mismatch(scope: &str, case: &ScopeCase) -> Option<&'static str> {
+    match case {
+        ScopeCase::Any => None,
+        ScopeCase::Lower => (scope.to_lowercase() != scope).then_some("lowercase"),
+        ScopeCase::Kebab => (!KEBAB_CASE_REGEX.is_match(scope)).then_some("kebab-case"),
+    }
+}
+
+static KEBAB_CASE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-z0-9]+(?:-[a-z0-9]+)*$").unwrap());
+
+/// Matches the text against a pattern where '*' stands for any sequence of characters.
+pub(crate) fn matches_wildcard(pattern: &str, text: &str) -> bool {
+    let regex = format!("^{}$", regex::escape(pattern).replace(r"\*", ".*"));
+    Regex::new(&regex).is_ok_and(|regex| regex.is_match(text))
+}
+
 // This is a best-effort heuristic, and will not catch all non-imperative messages.
-fn is_imperative(description: &str) -> Result<(), SumiError> {
+fn is_imperative(description: &str, language: &Language) -> Result<(), SumiError> {
     let first_word = description
         .split_whitespace()
         .next()
         .unwrap_or("")
         .to_string();
-    let first_word_lower = first_word.to_lowercase();
-    if NON_IMPERATIVE_VERBS.contains(first_word_lower.as_str()) {
-        return Err(SumiError::NonImperativeVerb { verb: first_word });
+    match language {
+        Language::En => {
+            let first_word_lower = first_word.to_lowercase();
+            if NON_IMPERATIVE_VERBS.contains(first_word_lower.as_str()) {
+                return Err(SumiError::NonImperativeVerb { verb: first_word });
+            }
+            Ok(())
+        }
+        Language::Es => check_verb_with_suggestion(&first_word, &NON_IMPERATIVE_VERBS_ES),
+        Language::De => {
+            check_verb_with_suggestion(&first_word, &NON_IMPERATIVE_VERBS_DE)?;
+            // German descriptions often end with the participle: "Fehler behoben".
+            let last_word = description.split_whitespace().last().unwrap_or("");
+            check_verb_with_suggestion(last_word, &PAST_PARTICIPLES_DE)
+        }
+    }
+}
+
+fn check_verb_with_suggestion(
+    word: &str,
+    verbs: &HashMap<String, &'static str>,
+) -> Result<(), SumiError> {
+    let word = word.trim_end_matches(|c: char| c.is_ascii_punctuation());
+    if let Some(infinitive) = verbs.get(&word.to_lowercase()) {
+        let suggestion = match word.chars().next() {
+            Some(c) if c.is_uppercase() => {
+                let mut chars = infinitive.chars();
+                chars
+                    .next()
+                    .map(|first| capitalize_title(first, chars.as_str()))
+                    .unwrap_or_default()
+            }
+            _ => infinitive.to_string(),
+        };
+        return Err(SumiError::NonImperativeVerbWithSuggestion {
+            verb: word.to_string(),
+            suggestion,
+        });
     }
     Ok(())
 }
@@ -386,53 +1011,185 @@ fn validate_no_period(header: &str) -> Result<(), SumiError> {
     Ok(())
 }
 
-fn validate_commit_type_and_scope(
-    parsed_commit: &ParsedCommit,
-    config: &Config,
-) -> Result<(), SumiError> {
-    let types_allowed = split_and_trim_list(&config.types_allowed);
-    let scopes_allowed = split_and_trim_list(&config.scopes_allowed);
+/// Allowed commit types: `types_allowed` plus the types defined in `[types]` tables.
+fn allowed_types(config: &Config) -> Vec<String> {
+    merge_with_defined(
+        split_and_trim_list(&config.types_allowed),
+        config.types.keys(),
+    )
+}
 
-    // Empty lists mean all types/scopes are allowed.
-    if types_allowed.is_empty() && scopes_allowed.is_empty() {
-        return Ok(());
+/// Allowed commit scopes: `scopes_allowed` plus the scopes defined in `[scopes]` tables.
+fn allowed_scopes(config: &Config) -> Vec<String> {
+    merge_with_defined(
+        split_and_trim_list(&config.scopes_allowed),
+        config.scopes.keys(),
+    )
+}
+
+fn merge_with_defined<'a>(
+    mut allowed: Vec<String>,
+    defined: impl Iterator<Item = &'a String>,
+) -> Vec<String> {
+    for name in defined {
+        if !allowed.contains(name) {
+            allowed.push(name.clone());
+        }
+    }
+    allowed
+}
+
+/// Returns the canonical type if `alias` is one of its aliases.
+pub(crate) fn canonical_type<'a>(config: &'a Config, alias: &str) -> Option<&'a str> {
+    config
+        .types
+        .iter()
+        .find(|(_, definition)| definition.aliases.iter().any(|a| a == alias))
+        .map(|(name, _)| name.as_str())
+}
+
+/// Returns the canonical scope if `alias` is one of its aliases.
+fn canonical_scope<'a>(config: &'a Config, alias: &str) -> Option<&'a str> {
+    config
+        .scopes
+        .iter()
+        .find(|(_, definition)| definition.aliases.iter().any(|a| a == alias))
+        .map(|(name, _)| name.as_str())
+}
+
+/// Suggests allowed types for an invalid one: its canonical type if it's an alias, or the closest matches.
+fn suggest_type(config: &Config, commit_type: &str, types_allowed: &[String]) -> Vec<String> {
+    match canonical_type(config, commit_type) {
+        Some(canonical) => vec![canonical.to_string()],
+        None => suggestions::suggest(commit_type, types_allowed),
     }
+}
+
+/// Suggests allowed scopes for an invalid one: its canonical scope if it's an alias, or the closest matches.
+fn suggest_scope(config: &Config, scope: &str, scopes_allowed: &[String]) -> Vec<String> {
+    match canonical_scope(config, scope) {
+        Some(canonical) => vec![canonical.to_string()],
+        None => suggestions::suggest(scope, scopes_allowed),
+    }
+}
+
+fn single_suggestion(mut suggestions: Vec<String>) -> Option<String> {
+    (suggestions.len() == 1).then(|| suggestions.remove(0))
+}
+
+/// Returns the allowed type to use instead of an invalid one, if the suggestion is unambiguous.
+fn type_replacement(config: &Config, commit_type: &str) -> Option<String> {
+    let types_allowed = allowed_types(config);
+    if types_allowed.is_empty() || types_allowed.iter().any(|t| t == commit_type) {
+        return None;
+    }
+    single_suggestion(suggest_type(config, commit_type, &types_allowed))
+}
 
+/// Returns the allowed scope to use instead of an invalid one, if the suggestion is unambiguous.
+/// Each component of a hierarchical scope is replaced separately.
+fn scope_replacement(config: &Config, scope: &str) -> Option<String> {
+    let scopes_allowed = allowed_scopes(config);
+    if scopes_allowed.is_empty() || scopes_allowed.iter().any(|s| s == scope) {
+        return None;
+    }
+    let components = scope_components(scope, config);
+    if components.len() == 1 {
+        return single_suggestion(suggest_scope(config, scope, &scopes_allowed));
+    }
+    let replaced: Vec<String> = components
+        .iter()
+        .map(|component| {
+            if scopes_allowed.iter().any(|s| s == component) {
+                return component.to_string();
+            }
+            single_suggestion(suggest_scope(config, component, &scopes_allowed))
+                .unwrap_or_else(|| component.to_string())
+        })
+        .collect();
+    Some(replaced.join(&config.scope_hierarchy_separator)).filter(|replaced| replaced != scope)
+}
+
+fn validate_commit_type_and_scope(parsed_commit: &ParsedCommit, config: &Config) -> Vec<SumiError> {
+    let mut errors = Vec::new();
+    let types_allowed = allowed_types(config);
+    let scopes_allowed = allowed_scopes(config);
+
+    // Empty lists mean all types/scopes are allowed.
     if let Some(commit_type) = &parsed_commit.commit_type {
         if !types_allowed.is_empty() && !types_allowed.contains(commit_type) {
-            return Err(SumiError::InvalidCommitType {
+            errors.push(SumiError::InvalidCommitType {
                 type_found: commit_type.clone(),
                 allowed_types: types_allowed.join(", "),
+                suggestions: suggest_type(config, commit_type, &types_allowed),
             });
         }
     }
 
-    if let Some(scope) = &parsed_commit.scope {
-        if !scopes_allowed.is_empty() && !scopes_allowed.contains(scope) {
-            return Err(SumiError::InvalidCommitScope {
-                scope_found: scope.clone(),
-                allowed_scopes: scopes_allowed.join(", "),
-            });
+    if scopes_allowed.is_empty() {
+        return errors;
+    }
+    for scope in parsed_commit.scopes.iter().flatten() {
+        // A hierarchical scope is valid if it's allowed as a whole, or if all its components are.
+        if scopes_allowed.contains(scope) {
+            continue;
         }
+        errors.extend(
+            scope_components(scope, config)
+                .into_iter()
+                .filter(|component| !scopes_allowed.iter().any(|s| s == component))
+                .map(|component| SumiError::InvalidCommitScope {
+                    scope_found: component.to_string(),
+                    allowed_scopes: scopes_allowed.join(", "),
+                    suggestions: suggest_scope(config, component, &scopes_allowed),
+                }),
+        );
     }
 
-    Ok(())
+    errors
 }
 
-fn validate_header_pattern(header: &str, pattern: &str) -> Result<(), SumiError> {
-    let re = Regex::new(pattern).map_err(|_| SumiError::InvalidRegexPattern {
-        pattern: pattern.to_string(),
-    })?;
-    if !re.is_match(header) {
-        return Err(SumiError::HeaderPatternMismatch {
-            pattern: pattern.to_string(),
-        });
+fn validate_header_patterns(header: &str, config: &Config) -> Vec<SumiError> {
+    let patterns = &config.compiled_header_patterns;
+    let matches = |p: &CompiledHeaderPattern| p.regex.is_match(header);
+    match config.header_patterns_match {
+        HeaderPatternsMatch::All => patterns
+            .iter()
+            .filter(|p| !matches(p))
+            .map(header_pattern_mismatch)
+            .collect(),
+        HeaderPatternsMatch::Any if patterns.iter().any(matches) => Vec::new(),
+        HeaderPatternsMatch::Any if patterns.len() == 1 => {
+            vec![header_pattern_mismatch(&patterns[0])]
+        }
+        HeaderPatternsMatch::Any => {
+            let patterns = patterns
+                .iter()
+                .map(|p| match &p.message {
+                    Some(message) => message.clone(),
+                    None => format!("'{}'", p.pattern),
+                })
+                .collect::<Vec<_>>();
+            vec![SumiError::NoHeaderPatternMatched {
+                patterns: patterns.join(", "),
+            }]
+        }
+    }
+}
+
+fn header_pattern_mismatch(pattern: &CompiledHeaderPattern) -> SumiError {
+    match &pattern.message {
+        Some(message) => SumiError::HeaderPatternMessage {
+            message: message.clone(),
+        },
+        None => SumiError::HeaderPatternMismatch {
+            pattern: pattern.pattern.clone(),
+        },
     }
-    Ok(())
 }
 
 // Helper function to process allowed types and scopes.
-fn split_and_trim_list(list: &[String]) -> Vec<String> {
+pub(crate) fn split_and_trim_list(list: &[String]) -> Vec<String> {
     list.iter()
         .flat_map(|s| s.split(',').map(|item| item.trim().to_string()))
         .filter(|x| !x.is_empty())
@@ -460,19 +1217,22 @@ fn handle_success(
     Ok(())
 }
 
-fn handle_failure(errors: &[SumiError], log_prefix: &str) -> Result<ParsedCommit, SumiError> {
-    display_errors(errors, log_prefix);
-    Err(SumiError::GeneralError {
-        details: format!(
-            "Found {} linting {}",
-            errors.len(),
-            pluralize(errors.len(), "error", "errors")
-        ),
+fn handle_failure(
+    errors: &[SumiError],
+    commit: &str,
+    config: &Config,
+    log_prefix: &str,
+) -> Result<ParsedCommit, SumiError> {
+    display_errors(errors, commit, config, log_prefix);
+    Err(SumiError::LintingErrors {
+        count: errors.len(),
+        error_or_errors: pluralize(errors.len(), "error", "errors").to_string(),
     })
 }
 
-fn display_errors(errors: &[SumiError], log_prefix: &str) {
+fn display_errors(errors: &[SumiError], commit: &str, config: &Config, log_prefix: &str) {
+    let color = config.color.enabled();
     for err in errors.iter() {
-        eprintln!("{log_prefix}️❗ {err}");
+        eprintln!("{}", render_diagnostic(err, commit, log_prefix, color));
     }
 }
@@ -1,3 +1,8 @@
 pub mod config_descriptions;
+pub mod forbidden_words;
 pub mod gitmoji;
 pub mod non_imperative_verbs;
+pub mod non_imperative_verbs_de;
+pub mod non_imperative_verbs_es;
+pub mod spelling_words;
+pub mod vague_descriptions;
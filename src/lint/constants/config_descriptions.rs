@@ -26,13 +26,39 @@ pub const DISPLAY: RuleDescription = RuleDescription {
 };
 pub const FORMAT: RuleDescription = RuleDescription {
     short: "Sets display format",
-    extra: Some("Options: 'cli', 'json', 'table', 'toml'"),
+    extra: Some("Options: 'cli', 'json', 'ndjson', 'table', 'toml'"),
+};
+pub const COLOR: RuleDescription = RuleDescription {
+    short: "Colors error diagnostics",
+    extra: Some("Options: 'auto', 'always', 'never'. 'auto' colors output to a terminal, unless NO_COLOR is set"),
 };
 pub const SPLIT_LINES: RuleDescription = RuleDescription {
     short: "Processes each non-empty line as an individual commit",
     extra: None,
 };
 
+pub const FIX: RuleDescription = RuleDescription {
+    short: "Fixes the commit header when possible",
+    extra: Some("Replaces type and scope aliases and typos with the allowed values"),
+};
+
+pub const SKIP_AUTHORS: RuleDescription = RuleDescription {
+    short: "List of authors whose commits are not linted in a revision range",
+    extra: Some("Matches the author name or email. Use '*' as a wildcard. Example: [\"*[bot]\"]"),
+};
+pub const SKIP_MERGE_COMMITS: RuleDescription = RuleDescription {
+    short: "Do not lint merge commits in a revision range",
+    extra: None,
+};
+pub const UNSUPPRESSIBLE_RULES: RuleDescription = RuleDescription {
+    short: "List of rules that can't be disabled with a Sumi-Disable trailer",
+    extra: Some("Use '*' as a wildcard. Example: [\"conventional\", \"breaking_*\"]"),
+};
+pub const BASELINE: RuleDescription = RuleDescription {
+    short: "Path to a baseline file of violations accepted in a revision range",
+    extra: Some("Create it with `git-sumi baseline`. Any other violation still fails"),
+};
+
 // Rules.
 pub const GITMOJI: RuleDescription = RuleDescription {
     short: "Include one valid Gitmoji",
@@ -44,11 +70,23 @@ pub const CONVENTIONAL: RuleDescription = RuleDescription {
     extra: Some("See https://www.conventionalcommits.org/"),
 };
 
+pub const CONVENTION: RuleDescription = RuleDescription {
+    short: "Commit message convention to follow",
+    extra: Some(
+        "Options: 'none', 'conventional', 'angular', 'kernel', 'gitmoji', 'jira', 'chromium'",
+    ),
+};
+
 pub const IMPERATIVE: RuleDescription = RuleDescription {
     short: "Use the imperative mood in the description",
     extra: Some("Example: 'Fix bug' instead of 'Fixed bug'"),
 };
 
+pub const LANGUAGE: RuleDescription = RuleDescription {
+    short: "Language of the commit messages for the imperative rule",
+    extra: Some("Options: 'de', 'en', 'es'"),
+};
+
 pub const WHITESPACE: RuleDescription = RuleDescription {
     short: "No leading, trailing, or consecutive spaces",
     extra: None,
@@ -64,16 +102,83 @@ pub const NO_PERIOD: RuleDescription = RuleDescription {
     extra: None,
 };
 
+pub const SPELLING: RuleDescription = RuleDescription {
+    short: "Check the spelling of the description and body",
+    extra: Some("Add project-specific words to '.sumi-words.txt', one per line"),
+};
+
+pub const FORBIDDEN_WORDS: RuleDescription = RuleDescription {
+    short: "Reject placeholder words, profanity and non-inclusive terms",
+    extra: Some("See the built-in list at https://sumi.rs/docs/rules#forbidden-words"),
+};
+
+pub const FORBIDDEN_WORDS_EXTRA: RuleDescription = RuleDescription {
+    short: "List of additional forbidden words",
+    extra: Some(
+        "Use 'word=replacement' to suggest an alternative. Example: [\"temp\", \"master=main\"]",
+    ),
+};
+
 pub const MAX_HEADER_LENGTH: RuleDescription = RuleDescription {
     short: "Header length limit",
     extra: Some("A value of 0 disables the rule"),
 };
 
+pub const MIN_DESCRIPTION_LENGTH: RuleDescription = RuleDescription {
+    short: "Minimum description length",
+    extra: Some("A value of 0 disables the rule"),
+};
+
+pub const MIN_DESCRIPTION_WORDS: RuleDescription = RuleDescription {
+    short: "Minimum number of words in the description",
+    extra: Some("A value of 0 disables the rule"),
+};
+
+pub const NO_VAGUE_DESCRIPTION: RuleDescription = RuleDescription {
+    short: "Reject vague descriptions like 'update' or 'misc'",
+    extra: None,
+};
+
 pub const MAX_BODY_LENGTH: RuleDescription = RuleDescription {
     short: "Body line length limit",
+    extra: Some("Measured in terminal columns. A value of 0 disables the rule"),
+};
+
+pub const BODY_LENGTH_EXEMPTIONS: RuleDescription = RuleDescription {
+    short: "Body lines exempt from the length limit",
+    extra: Some("Options: 'url', 'code', 'quote', 'trailer'. Example: [\"url\", \"code\"]"),
+};
+
+pub const BODY_REQUIRED_TYPES: RuleDescription = RuleDescription {
+    short: "List of commit types that require a body",
+    extra: Some("An empty list disables the rule. Example: [\"feat\", \"fix\"]"),
+};
+
+pub const BODY_REQUIRED_HEADER_LENGTH: RuleDescription = RuleDescription {
+    short: "Require a body when the header is longer than this",
+    extra: Some("A value of 0 disables the rule"),
+};
+
+pub const BODY_REQUIRED_CAPTURES: RuleDescription = RuleDescription {
+    short: "List of header pattern captures that require a body",
+    extra: Some("Names of capture groups in 'header_pattern'. Example: [\"breaking\"]"),
+};
+
+pub const MIN_BODY_LENGTH: RuleDescription = RuleDescription {
+    short: "Minimum body length",
     extra: Some("A value of 0 disables the rule"),
 };
 
+pub const NO_REPEATED_HEADER: RuleDescription = RuleDescription {
+    short: "Body must not just repeat the header",
+    extra: None,
+};
+
+pub const CONSISTENT_BULLETS: RuleDescription = RuleDescription {
+    short: "Use the same marker for all bullets in a list",
+    extra: Some("Example: don't mix '-' and '*'"),
+};
+
 pub const SCOPES_ALLOWED: RuleDescription = RuleDescription {
     short: "List of allowed commit scopes",
     extra: Some("An empty list allows all scopes. Example: [\"docs\", \"cli\"]"),
@@ -84,6 +189,88 @@ pub const TYPES_ALLOWED: RuleDescription = RuleDescription {
     extra: Some("An empty list allows all types. Example: [\"feat\", \"fix\", \"docs\"]"),
 };
 
+pub const TYPES: RuleDescription = RuleDescription {
+    short: "Commit types",
+    extra: Some("Defined in [types.<name>] tables with 'description', 'aliases' and 'bump'"),
+};
+
+pub const SCOPES: RuleDescription = RuleDescription {
+    short: "Commit scopes",
+    extra: Some("Defined in [scopes.<name>] tables with 'description' and 'aliases'"),
+};
+
+pub const LOWERCASE_TYPE: RuleDescription = RuleDescription {
+    short: "Commit type must be lowercase",
+    extra: None,
+};
+
+pub const SCOPE_REQUIRED: RuleDescription = RuleDescription {
+    short: "Require a commit scope",
+    extra: None,
+};
+
+pub const SCOPE_REQUIRED_TYPES: RuleDescription = RuleDescription {
+    short: "List of commit types that require a scope",
+    extra: Some("Example: [\"feat\", \"fix\"]"),
+};
+
+pub const SCOPE_CASE: RuleDescription = RuleDescription {
+    short: "Case style of the commit scope",
+    extra: Some("Options: 'any', 'lower', 'kebab'"),
+};
+
+pub const NO_MULTIPLE_SCOPES: RuleDescription = RuleDescription {
+    short: "Allow only one commit scope",
+    extra: Some("Multiple scopes are separated by commas, like 'feat(api,cli)'"),
+};
+
+pub const SCOPE_DELIMITERS: RuleDescription = RuleDescription {
+    short: "Characters that separate multiple scopes",
+    extra: Some("Each character is a delimiter. An empty value uses ','. Example: \",|\""),
+};
+
+pub const SCOPE_HIERARCHY_SEPARATOR: RuleDescription = RuleDescription {
+    short: "Separator between the components of a hierarchical scope",
+    extra: Some(
+        "Components are validated individually. An empty value disables it. Example: \"/\"",
+    ),
+};
+
+pub const SINGLE_SPACE_AFTER_COLON: RuleDescription = RuleDescription {
+    short: "Require exactly one space after the colon in the header",
+    extra: None,
+};
+
+pub const BREAKING_REQUIRES_FOOTER: RuleDescription = RuleDescription {
+    short: "Require a 'BREAKING CHANGE:' footer when the header uses '!'",
+    extra: None,
+};
+
+pub const BREAKING_REQUIRES_BANG: RuleDescription = RuleDescription {
+    short: "Require '!' in the header when there's a 'BREAKING CHANGE:' footer",
+    extra: None,
+};
+
+pub const MIN_BREAKING_DESCRIPTION_LENGTH: RuleDescription = RuleDescription {
+    short: "Minimum length of the breaking change description",
+    extra: Some("A value of 0 disables the rule"),
+};
+
+pub const BREAKING_FORBIDDEN_TYPES: RuleDescription = RuleDescription {
+    short: "List of commit types that can't be breaking changes",
+    extra: Some("Example: [\"docs\", \"chore\"]"),
+};
+
+pub const BREAKING_FORBIDDEN_SCOPES: RuleDescription = RuleDescription {
+    short: "List of scopes that can't be breaking changes",
+    extra: Some("Example: [\"deps\"]"),
+};
+
+pub const BREAKING_FORBIDDEN_BRANCHES: RuleDescription = RuleDescription {
+    short: "List of branches where breaking changes aren't allowed",
+    extra: Some("Supports '*' wildcards. Example: [\"release/*\", \"v1.x\"]"),
+};
+
 pub const HEADER_PATTERN: RuleDescription = RuleDescription {
     short: "Header must match regex pattern",
     extra: Some("Example: '^JIRA-\\d+:'"),
@@ -93,3 +280,18 @@ pub const STRIP_HEADER_PATTERN: RuleDescription = RuleDescription {
     short: "Remove header pattern before running other validation rules",
     extra: None,
 };
+
+pub const HEADER_PATTERNS: RuleDescription = RuleDescription {
+    short: "Header patterns",
+    extra: Some("Defined in [[header_patterns]] tables with 'pattern' and 'message'"),
+};
+
+pub const HEADER_PATTERNS_MATCH: RuleDescription = RuleDescription {
+    short: "Whether headers must match all or any of the header patterns",
+    extra: Some("Options: 'all', 'any'. Applies to 'header_pattern' and [[header_patterns]]"),
+};
+
+pub const HEADER_FORMAT: RuleDescription = RuleDescription {
+    short: "Header must follow a template",
+    extra: Some("Placeholders: {gitmoji}, {type}, {scope}, {breaking}, {description}, {ticket}. Example: \"{type}({scope}): {description}\""),
+};
@@ -0,0 +1,33 @@
+// Built-in list for the forbidden words rule: (term, suggested replacement).
+// Terms are matched case-insensitively on word boundaries.
+pub const DEFAULT_FORBIDDEN_WORDS: &[(&str, Option<&str>)] = &[
+    // Placeholders and unfinished work.
+    ("asdf", None),
+    ("qwerty", None),
+    ("wip", None),
+    ("xxx", None),
+    // Profanity.
+    ("crap", None),
+    ("damn", None),
+    ("fuck", None),
+    ("fucking", None),
+    ("shit", None),
+    ("wtf", None),
+    // Non-inclusive terms.
+    ("blacklist", Some("denylist")),
+    ("blacklisted", Some("denylisted")),
+    ("blacklisting", Some("denylisting")),
+    ("blacklists", Some("denylists")),
+    ("whitelist", Some("allowlist")),
+    ("whitelisted", Some("allowlisted")),
+    ("whitelisting", Some("allowlisting")),
+    ("whitelists", Some("allowlists")),
+    ("slave", Some("replica")),
+    ("slaves", Some("replicas")),
+    ("master/slave", Some("primary/replica")),
+    ("grandfathered", Some("legacy")),
+    ("manpower", Some("workforce")),
+    ("man-hours", Some("person-hours")),
+    ("sanity check", Some("confidence check")),
+    ("dummy value", Some("placeholder value")),
+];
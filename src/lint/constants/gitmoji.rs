@@ -2,7 +2,7 @@
 // The unicode emojis were normalised using GNU sed:
 // sed -i 's/\xEF\xB8\x8F//g' gitmoji.rs
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::LazyLock;
 
 pub static UNICODE_EMOJIS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
@@ -162,3 +162,83 @@ pub static STRING_EMOJIS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
         ":airplane:",
     ])
 });
+
+/// Gitmoji names (shortcodes without colons), by unicode emoji.
+pub static GITMOJI_NAMES: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
+    HashMap::from([
+        ("🎨", "art"),
+        ("⚡", "zap"),
+        ("🔥", "fire"),
+        ("🐛", "bug"),
+        ("🚑", "ambulance"),
+        ("✨", "sparkles"),
+        ("📝", "memo"),
+        ("🚀", "rocket"),
+        ("💄", "lipstick"),
+        ("🎉", "tada"),
+        ("✅", "white_check_mark"),
+        ("🔒", "lock"),
+        ("🔐", "closed_lock_with_key"),
+        ("🔖", "bookmark"),
+        ("🚨", "rotating_light"),
+        ("🚧", "construction"),
+        ("💚", "green_heart"),
+        ("⬇", "arrow_down"),
+        ("⬆", "arrow_up"),
+        ("📌", "pushpin"),
+        ("👷", "construction_worker"),
+        ("📈", "chart_with_upwards_trend"),
+        ("♻", "recycle"),
+        ("➕", "heavy_plus_sign"),
+        ("➖", "heavy_minus_sign"),
+        ("🔧", "wrench"),
+        ("🔨", "hammer"),
+        ("🌐", "globe_with_meridians"),
+        ("✏", "pencil2"),
+        ("💩", "poop"),
+        ("⏪", "rewind"),
+        ("🔀", "twisted_rightwards_arrows"),
+        ("📦", "package"),
+        ("👽", "alien"),
+        ("🚚", "truck"),
+        ("📄", "page_facing_up"),
+        ("💥", "boom"),
+        ("🍱", "bento"),
+        ("♿", "wheelchair"),
+        ("💡", "bulb"),
+        ("🍻", "beers"),
+        ("💬", "speech_balloon"),
+        ("🗃", "card_file_box"),
+        ("🔊", "loud_sound"),
+        ("🔇", "mute"),
+        ("👥", "busts_in_silhouette"),
+        ("🚸", "children_crossing"),
+        ("🏗", "building_construction"),
+        ("📱", "iphone"),
+        ("🤡", "clown_face"),
+        ("🥚", "egg"),
+        ("🙈", "see_no_evil"),
+        ("📸", "camera_flash"),
+        ("⚗", "alembic"),
+        ("🔍", "mag"),
+        ("🏷", "label"),
+        ("🌱", "seedling"),
+        ("🚩", "triangular_flag_on_post"),
+        ("🥅", "goal_net"),
+        ("💫", "dizzy"),
+        ("🗑", "wastebasket"),
+        ("🛂", "passport_control"),
+        ("🩹", "adhesive_bandage"),
+        ("🧐", "monocle_face"),
+        ("⚰", "coffin"),
+        ("🧪", "test_tube"),
+        ("👔", "necktie"),
+        ("🩺", "stethoscope"),
+        ("🧱", "bricks"),
+        ("🧑‍💻", "technologist"),
+        ("💸", "money_with_wings"),
+        ("🧵", "thread"),
+        ("🦺", "safety_vest"),
+        ("✈", "airplane"),
+    ])
+});
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+// Verbs ending in '-ieren' form their participle without the 'ge-' prefix:
+// aktualisieren → aktualisiert.
+const IEREN_VERBS: &[&str] = &[
+    "aktivieren",
+    "aktualisieren",
+    "deaktivieren",
+    "definieren",
+    "dokumentieren",
+    "exportieren",
+    "formatieren",
+    "generieren",
+    "ignorieren",
+    "implementieren",
+    "importieren",
+    "initialisieren",
+    "installieren",
+    "integrieren",
+    "kommentieren",
+    "konfigurieren",
+    "korrigieren",
+    "migrieren",
+    "modifizieren",
+    "optimieren",
+    "refaktorieren",
+    "refaktorisieren",
+    "reorganisieren",
+    "reparieren",
+    "synchronisieren",
+    "validieren",
+];
+
+// (infinitive, past participle, other non-imperative forms).
+const OTHER_VERBS: &[(&str, &str, &[&str])] = &[
+    ("ändern", "geändert", &["ändert", "änderte"]),
+    ("anpassen", "angepasst", &["passt"]),
+    ("aufräumen", "aufgeräumt", &["räumt"]),
+    ("aufteilen", "aufgeteilt", &[]),
+    ("beheben", "behoben", &["behebt", "behob"]),
+    ("bereinigen", "bereinigt", &["bereinigte"]),
+    ("beschleunigen", "beschleunigt", &["beschleunigte"]),
+    ("einführen", "eingeführt", &["führt"]),
+    ("entfernen", "entfernt", &["entfernte"]),
+    ("ergänzen", "ergänzt", &["ergänzte"]),
+    ("erhöhen", "erhöht", &["erhöhte"]),
+    ("erlauben", "erlaubt", &["erlaubte"]),
+    ("ermöglichen", "ermöglicht", &["ermöglichte"]),
+    ("ersetzen", "ersetzt", &["ersetzte"]),
+    ("erstellen", "erstellt", &["erstellte"]),
+    ("erweitern", "erweitert", &["erweiterte"]),
+    ("fixen", "gefixt", &["fixt", "fixte"]),
+    ("hinzufügen", "hinzugefügt", &["fügt"]),
+    ("laden", "geladen", &["lädt", "lud"]),
+    ("löschen", "gelöscht", &["löscht", "löschte"]),
+    ("machen", "gemacht", &["macht", "machte"]),
+    ("prüfen", "geprüft", &["prüft", "prüfte"]),
+    ("schreiben", "geschrieben", &["schreibt", "schrieb"]),
+    ("setzen", "gesetzt", &["setzt", "setzte"]),
+    ("speichern", "gespeichert", &["speichert", "speicherte"]),
+    ("testen", "getestet", &["testet", "testete"]),
+    ("trennen", "getrennt", &["trennt", "trennte"]),
+    ("überarbeiten", "überarbeitet", &["überarbeitete"]),
+    ("übersetzen", "übersetzt", &["übersetzte"]),
+    ("umbenennen", "umbenannt", &["benennt"]),
+    ("unterstützen", "unterstützt", &["unterstützte"]),
+    ("verbessern", "verbessert", &["verbesserte"]),
+    ("vereinfachen", "vereinfacht", &["vereinfachte"]),
+    ("verhindern", "verhindert", &["verhinderte"]),
+    ("verschieben", "verschoben", &["verschiebt", "verschob"]),
+    ("verwenden", "verwendet", &["verwendete"]),
+    ("zurücksetzen", "zurückgesetzt", &[]),
+    ("zusammenführen", "zusammengeführt", &[]),
+];
+
+fn generate_participles() -> HashMap<String, &'static str> {
+    let mut participles = HashMap::new();
+    for &infinitive in IEREN_VERBS {
+        let stem = infinitive.strip_suffix("en").unwrap_or(infinitive);
+        participles.insert(format!("{stem}t"), infinitive);
+    }
+    for &(infinitive, participle, _) in OTHER_VERBS {
+        participles.insert(participle.to_string(), infinitive);
+    }
+    participles
+}
+
+fn generate_verbs() -> HashMap<String, &'static str> {
+    let mut verbs = PAST_PARTICIPLES_DE.clone();
+    for &infinitive in IEREN_VERBS {
+        let stem = infinitive.strip_suffix("en").unwrap_or(infinitive);
+        verbs.insert(format!("{stem}te"), infinitive);
+    }
+    for &(infinitive, _, forms) in OTHER_VERBS {
+        for &form in forms {
+            verbs.insert(form.to_string(), infinitive);
+        }
+    }
+    verbs
+}
+
+/// Maps German past participles to their infinitive.
+/// German commit messages often end with the participle ("Fehler behoben"),
+/// so these are also checked at the end of the description.
+pub static PAST_PARTICIPLES_DE: LazyLock<HashMap<String, &'static str>> =
+    LazyLock::new(generate_participles);
+
+/// Maps German non-imperative verb forms common to commit messages to their infinitive.
+pub static NON_IMPERATIVE_VERBS_DE: LazyLock<HashMap<String, &'static str>> =
+    LazyLock::new(generate_verbs);
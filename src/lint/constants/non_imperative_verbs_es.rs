@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+// Regular verbs whose participle, gerund and preterite forms can be derived from the infinitive.
+const REGULAR_VERBS: &[&str] = &[
+    "activar",
+    "actualizar",
+    "agregar",
+    "ajustar",
+    "añadir",
+    "aplicar",
+    "arreglar",
+    "aumentar",
+    "borrar",
+    "bloquear",
+    "cambiar",
+    "cargar",
+    "comprobar",
+    "configurar",
+    "crear",
+    "definir",
+    "depurar",
+    "desactivar",
+    "deshabilitar",
+    "dividir",
+    "documentar",
+    "eliminar",
+    "evitar",
+    "exportar",
+    "formatear",
+    "fusionar",
+    "generar",
+    "guardar",
+    "habilitar",
+    "implementar",
+    "importar",
+    "incorporar",
+    "inicializar",
+    "instalar",
+    "integrar",
+    "limpiar",
+    "manejar",
+    "mejorar",
+    "migrar",
+    "modificar",
+    "mover",
+    "ocultar",
+    "optimizar",
+    "ordenar",
+    "permitir",
+    "preparar",
+    "probar",
+    "publicar",
+    "quitar",
+    "reducir",
+    "reemplazar",
+    "refactorizar",
+    "registrar",
+    "renombrar",
+    "reorganizar",
+    "restaurar",
+    "revisar",
+    "separar",
+    "simplificar",
+    "solucionar",
+    "subir",
+    "unificar",
+    "usar",
+    "utilizar",
+    "validar",
+];
+
+// Forms that don't follow the regular conjugation patterns.
+const IRREGULAR_FORMS: &[(&str, &str)] = &[
+    ("convertido", "convertir"),
+    ("convirtiendo", "convertir"),
+    ("convirtió", "convertir"),
+    ("corregido", "corregir"),
+    ("corrigiendo", "corregir"),
+    ("corrigió", "corregir"),
+    ("corregí", "corregir"),
+    ("deshecho", "deshacer"),
+    ("deshaciendo", "deshacer"),
+    ("deshizo", "deshacer"),
+    ("escrito", "escribir"),
+    ("escribiendo", "escribir"),
+    ("escribió", "escribir"),
+    ("extraído", "extraer"),
+    ("extrayendo", "extraer"),
+    ("extrajo", "extraer"),
+    ("hecho", "hacer"),
+    ("haciendo", "hacer"),
+    ("hizo", "hacer"),
+    ("incluido", "incluir"),
+    ("incluyendo", "incluir"),
+    ("incluyó", "incluir"),
+    ("puesto", "poner"),
+    ("poniendo", "poner"),
+    ("puso", "poner"),
+    ("resuelto", "resolver"),
+    ("resolviendo", "resolver"),
+    ("resolvió", "resolver"),
+    ("revertido", "revertir"),
+    ("revirtiendo", "revertir"),
+    ("revirtió", "revertir"),
+    ("sustituido", "sustituir"),
+    ("sustituyendo", "sustituir"),
+    ("sustituyó", "sustituir"),
+    ("traducido", "traducir"),
+    ("traduciendo", "traducir"),
+    ("tradujo", "traducir"),
+];
+
+fn conjugate(infinitive: &str) -> Vec<String> {
+    let (stem, ending) = infinitive.split_at(infinitive.len() - 2);
+    match ending {
+        "ar" => {
+            // First person preterite changes spelling before 'é': actualicé, agregué, modifiqué.
+            let first_person_preterite = if let Some(s) = stem.strip_suffix('z') {
+                format!("{s}cé")
+            } else if let Some(s) = stem.strip_suffix('g') {
+                format!("{s}gué")
+            } else if let Some(s) = stem.strip_suffix('c') {
+                format!("{s}qué")
+            } else {
+                format!("{stem}é")
+            };
+            vec![
+                format!("{stem}ado"),
+                format!("{stem}ada"),
+                format!("{stem}ados"),
+                format!("{stem}adas"),
+                format!("{stem}ando"),
+                format!("{stem}ó"),
+                format!("{stem}an"),
+                first_person_preterite,
+            ]
+        }
+        _ => vec![
+            format!("{stem}ido"),
+            format!("{stem}ida"),
+            format!("{stem}idos"),
+            format!("{stem}idas"),
+            format!("{stem}iendo"),
+            format!("{stem}ió"),
+            format!("{stem}í"),
+            format!("{stem}en"),
+        ],
+    }
+}
+
+fn generate_verbs() -> HashMap<String, &'static str> {
+    let mut verbs = HashMap::new();
+    for &infinitive in REGULAR_VERBS {
+        for form in conjugate(infinitive) {
+            verbs.insert(form, infinitive);
+        }
+    }
+    for &(form, infinitive) in IRREGULAR_FORMS {
+        verbs.insert(form.to_string(), infinitive);
+    }
+    verbs
+}
+
+/// Maps Spanish non-imperative verb forms common to commit messages to their infinitive.
+pub static NON_IMPERATIVE_VERBS_ES: LazyLock<HashMap<String, &'static str>> =
+    LazyLock::new(generate_verbs);
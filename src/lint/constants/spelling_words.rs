@@ -0,0 +1,110 @@
+// The English word list was dumped from the US region of Vim's `en` spell file, built from SCOWL.
+// See english_words.NOTICE for its provenance and license.
+
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+const ENGLISH_WORDS: &str = include_str!("english_words.txt");
+
+// Words common to commit messages that are missing from the English word list.
+const TECHNICAL_WORDS: &[&str] = &[
+    "args",
+    "async",
+    "auth",
+    "authn",
+    "authz",
+    "backend",
+    "bool",
+    "booleans",
+    "bugfix",
+    "builtins",
+    "cfg",
+    "clippy",
+    "codegen",
+    "codeowners",
+    "config",
+    "configs",
+    "csv",
+    "datetime",
+    "dedup",
+    "dedupe",
+    "deno",
+    "dependabot",
+    "deps",
+    "dockerfile",
+    "dotfile",
+    "dotfiles",
+    "enum",
+    "enums",
+    "env",
+    "envs",
+    "eslint",
+    "frontend",
+    "gitattributes",
+    "gitignore",
+    "gitlab",
+    "gitmoji",
+    "gitmojis",
+    "hotfix",
+    "hotfixes",
+    "init",
+    "js",
+    "json",
+    "jsonl",
+    "jsx",
+    "linter",
+    "linters",
+    "localhost",
+    "lockfile",
+    "lockfiles",
+    "mixin",
+    "mixins",
+    "monorepo",
+    "npm",
+    "npx",
+    "oauth",
+    "params",
+    "pnpm",
+    "prerelease",
+    "prereleases",
+    "rebase",
+    "rebased",
+    "rebasing",
+    "regexes",
+    "runtimes",
+    "rustfmt",
+    "semver",
+    "serde",
+    "serializer",
+    "stderr",
+    "stdin",
+    "stdout",
+    "struct",
+    "structs",
+    "submodule",
+    "submodules",
+    "sumi",
+    "svg",
+    "todo",
+    "todos",
+    "tokio",
+    "toml",
+    "tsx",
+    "unstaged",
+    "utf",
+    "wasm",
+    "webhook",
+    "webhooks",
+    "whitespace",
+    "wip",
+    "yaml",
+    "yml",
+];
+
+/// A static HashSet containing lowercase English words and common technical terms.
+pub static KNOWN_WORDS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    ENGLISH_WORDS
+        .lines()
+        .chain(TECHNICAL_WORDS.iter().copied())
+        .collect()
+});
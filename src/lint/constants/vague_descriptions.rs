@@ -0,0 +1,31 @@
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+/// Descriptions that don't say what changed or why, in lowercase.
+pub static VAGUE_DESCRIPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    HashSet::from([
+        "bugfix",
+        "change",
+        "changes",
+        "cleanup",
+        "edit",
+        "edits",
+        "fix",
+        "fixes",
+        "improvements",
+        "minor",
+        "minor changes",
+        "minor fixes",
+        "misc",
+        "more",
+        "refactor",
+        "small changes",
+        "small fixes",
+        "stuff",
+        "tweak",
+        "tweaks",
+        "update",
+        "updates",
+        "various",
+    ])
+});
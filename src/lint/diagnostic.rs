@@ -0,0 +1,282 @@
+use super::errors::SumiError;
+use super::WHITESPACE_REGEX;
+use std::ops::Range;
+use unicode_width::UnicodeWidthChar;
+
+const RED: &str = "\x1b[1;31m";
+const BLUE: &str = "\x1b[1;34m";
+const CYAN: &str = "\x1b[1;36m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// The part of the commit message an error points at.
+struct Location {
+    /// 1-based, like in error messages.
+    line_number: usize,
+    /// Character ranges to underline.
+    ranges: Vec<Range<usize>>,
+}
+
+struct Painter {
+    color: bool,
+}
+
+impl Painter {
+    fn paint(&self, style: &str, text: &str) -> String {
+        if self.color && !text.is_empty() {
+            format!("{style}{text}{RESET}")
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// Renders an error like a compiler diagnostic:
+///
+/// ```text
+/// error[no_period]: Header must not end with a period
+///  --> line 1, column 21
+///   |
+/// 1 | feat: add exit codes.
+///   |                     ^
+///   = help: remove the period at the end of the header
+/// ```
+pub fn render_diagnostic(err: &SumiError, commit: &str, log_prefix: &str, color: bool) -> String {
+    let painter = Painter { color };
+    let lines: Vec<&str> = commit.lines().collect();
+    let location = locate(err, &lines);
+    let gutter_width = location
+        .as_ref()
+        .map_or(1, |location| location.line_number.to_string().len());
+    let gutter = " ".repeat(gutter_width);
+
+    let title = match err.rule() {
+        Some(rule) => format!("error[{rule}]"),
+        None => "error".to_string(),
+    };
+    let mut output = vec![format!(
+        "{log_prefix}{}{}",
+        painter.paint(RED, &title),
+        painter.paint(BOLD, &format!(": {err}"))
+    )];
+
+    if let Some(location) = location {
+        let line = lines[location.line_number - 1].replace('\t', " ");
+        let column = location.ranges[0].start + 1;
+        let bar = painter.paint(BLUE, "|");
+        output.push(format!(
+            "{gutter}{} line {}, column {column}",
+            painter.paint(BLUE, "-->"),
+            location.line_number
+        ));
+        output.push(format!("{gutter} {bar}"));
+        output.push(format!(
+            "{} {bar} {line}",
+            painter.paint(BLUE, &location.line_number.to_string())
+        ));
+        output.push(format!(
+            "{gutter} {bar} {}",
+            painter.paint(RED, &underline(&line, &location.ranges))
+        ));
+    }
+
+    if let Some(help) = err.help() {
+        output.push(format!(
+            "{gutter} {} {}: {help}",
+            painter.paint(BLUE, "="),
+            painter.paint(CYAN, "help")
+        ));
+    }
+    output.join("\n")
+}
+
+/// Carets under the ranges, aligned to the line's display width.
+fn underline(line: &str, ranges: &[Range<usize>]) -> String {
+    let is_marked = |index: usize| ranges.iter().any(|range| range.contains(&index));
+    let mut underline = String::new();
+    let mut length = 0;
+    for (index, c) in line.chars().enumerate() {
+        let marker = if is_marked(index) { "^" } else { " " };
+        underline.push_str(&marker.repeat(c.width().unwrap_or(0).max(1)));
+        length = index + 1;
+    }
+    // Ranges past the end of the line, like a missing character.
+    let end = ranges.iter().map(|range| range.end).max().unwrap_or(0);
+    for index in length..end {
+        underline.push(if is_marked(index) { '^' } else { ' ' });
+    }
+    underline.trim_end().to_string()
+}
+
+fn locate(err: &SumiError, lines: &[&str]) -> Option<Location> {
+    use SumiError::*;
+    let header = lines.first().copied().unwrap_or("");
+    let in_header = |range: Option<Range<usize>>| {
+        range.map(|range| Location {
+            line_number: 1,
+            ranges: vec![range],
+        })
+    };
+    let in_line = |line_number: usize, find: &dyn Fn(&str) -> Option<Range<usize>>| {
+        let line = lines.get(line_number.checked_sub(1)?)?;
+        find(line).map(|range| Location {
+            line_number,
+            ranges: vec![range],
+        })
+    };
+
+    match err {
+        HeaderEndsWithPeriod => {
+            let end = header.trim_end().chars().count();
+            in_header(end.checked_sub(1).map(|start| start..end))
+        }
+        LineTooLong {
+            line_number,
+            max_length,
+            ..
+        } => in_line(*line_number, &|line| {
+            let length = line.chars().count();
+            // The header length counts characters; body lines count display width.
+            if *line_number == 1 {
+                (length > *max_length).then_some(*max_length..length)
+            } else {
+                past_width(line, *max_length).map(|start| start..length)
+            }
+        }),
+        WhitespaceIssues { line_number, .. } => {
+            let line = lines.get(line_number.checked_sub(1)?)?;
+            let ranges: Vec<Range<usize>> = WHITESPACE_REGEX
+                .find_iter(line)
+                .map(|found| char_range(line, found.range()))
+                .collect();
+            (!ranges.is_empty()).then_some(Location {
+                line_number: *line_number,
+                ranges,
+            })
+        }
+        SeparateHeaderFromBody => in_line(2, &|line| whole_line(line)),
+        BodyRepeatsHeader { line_number } => in_line(*line_number, &|line| whole_line(line)),
+        UnknownWord {
+            word, line_number, ..
+        } => in_line(*line_number, &|line| find_word(line, word)),
+        ForbiddenWord {
+            word,
+            line_number,
+            column,
+            ..
+        } => in_line(*line_number, &|_| {
+            let start = column.checked_sub(1)?;
+            Some(start..start + word.chars().count())
+        }),
+        InconsistentBulletMarker {
+            marker,
+            line_number,
+            ..
+        } => in_line(*line_number, &|line| find(line, marker)),
+        NonImperativeVerb { verb } | NonImperativeVerbWithSuggestion { verb, .. } => {
+            in_header(find_word(header, verb))
+        }
+        DescriptionNotLowercase {
+            lowercase_header: corrected,
+        }
+        | DescriptionNotTitleCase {
+            capitalized_description: corrected,
+        } => in_header(find_description_start(header, corrected)),
+        CommitTypeNotLowercase { type_found }
+        | InvalidCommitType { type_found, .. }
+        | BreakingChangeForbiddenForType {
+            commit_type: type_found,
+        } => in_header(find_word(header, type_found)),
+        InvalidCommitScope {
+            scope_found: scope, ..
+        }
+        | InvalidScopeCase { scope, .. }
+        | MultipleScopes { scope }
+        | BreakingChangeForbiddenForScope { scope } => in_header(find_scope(header, scope)),
+        SpacesAfterColon { spaces } => in_header(header.find(':').map(|colon| {
+            let start = header[..=colon].chars().count();
+            start..start + (*spaces).max(1)
+        })),
+        BreakingChangeMissingBang => in_header(header.find(':').map(|colon| {
+            let start = header[..colon].chars().count();
+            start..start + 1
+        })),
+        InvalidEmoji { emoji } => in_header(find(header, emoji)),
+        VagueDescription { description } => in_header(find(header, description)),
+        FailedConventionalParse {
+            line_number,
+            column,
+            ..
+        } => in_line(*line_number, &|_| Some(column - 1..*column)),
+        EmptyScope { column } => in_header(Some(column - 1..column + 1)),
+        UnknownSuppressedRule { rule, line_number }
+        | SuppressionForbidden { rule, line_number } => {
+            in_line(*line_number, &|line| find_word(line, rule))
+        }
+        HeaderFormatMismatch { column, .. } => {
+            in_header(column.checked_sub(1).map(|start| start..start + 1))
+        }
+        _ => None,
+    }
+}
+
+fn char_range(line: &str, bytes: Range<usize>) -> Range<usize> {
+    let start = line[..bytes.start].chars().count();
+    start..start + line[bytes].chars().count()
+}
+
+/// Index of the first character that ends past `max_width` columns.
+fn past_width(line: &str, max_width: usize) -> Option<usize> {
+    let mut width = 0;
+    line.chars().position(|c| {
+        width += c.width().unwrap_or(0);
+        width > max_width
+    })
+}
+
+fn whole_line(line: &str) -> Option<Range<usize>> {
+    Some(0..line.chars().count().max(1))
+}
+
+fn find(line: &str, text: &str) -> Option<Range<usize>> {
+    if text.is_empty() {
+        return None;
+    }
+    let start = line.find(text)?;
+    Some(char_range(line, start..start + text.len()))
+}
+
+/// Like `find`, skipping matches inside longer words.
+fn find_word(line: &str, word: &str) -> Option<Range<usize>> {
+    if word.is_empty() {
+        return None;
+    }
+    let is_word_char = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    line.match_indices(word)
+        .find(|(start, _)| {
+            !is_word_char(line[..*start].chars().next_back())
+                && !is_word_char(line[start + word.len()..].chars().next())
+        })
+        .map(|(start, _)| char_range(line, start..start + word.len()))
+}
+
+/// The scope inside the header's parentheses.
+fn find_scope(header: &str, scope: &str) -> Option<Range<usize>> {
+    let open = header.find('(')?;
+    let range = find(&header[open..], scope)?;
+    let offset = header[..open].chars().count();
+    Some(range.start + offset..range.end + offset)
+}
+
+/// The first letter of the description, given the description with that letter corrected.
+fn find_description_start(header: &str, corrected: &str) -> Option<Range<usize>> {
+    let mut chars = corrected.chars();
+    chars.next()?;
+    let rest = chars.as_str();
+    if rest.is_empty() {
+        return None;
+    }
+    let rest_start = header.rfind(rest)?;
+    let start = header[..rest_start].chars().count().checked_sub(1)?;
+    Some(start..start + 1)
+}
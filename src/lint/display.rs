@@ -1,6 +1,12 @@
 use super::errors::SumiError;
+use super::suppression::Suppression;
+use super::CheckedCommit;
+use crate::baseline::accept_baselined;
 use crate::config::ParsedCommitDisplayFormat;
+use crate::git::{CommitMetadata, GitCommit};
 use crate::parser::ParsedCommit;
+use serde::Serialize;
+use std::collections::BTreeSet;
 use tabled::{
     builder::Builder,
     settings::{object::Rows, Remove, Style},
@@ -15,6 +21,7 @@ pub fn display_parsed_commit(
             display_parsed_commit_as_table(commit, ParsedCommitDisplayFormat::Cli)?
         }
         ParsedCommitDisplayFormat::Json => display_parsed_commit_as_json(commit)?,
+        ParsedCommitDisplayFormat::Ndjson => println!("{}", serialize_to_json_line(commit)?),
         ParsedCommitDisplayFormat::Table => {
             display_parsed_commit_as_table(commit, ParsedCommitDisplayFormat::Table)?
         }
@@ -23,25 +30,151 @@ pub fn display_parsed_commit(
     Ok(())
 }
 
+/// Lint status of a commit in a range document.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintStatus {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+/// A commit (or line, with `split_lines`) of a linted range.
+#[derive(Debug, Serialize)]
+pub struct RangeEntry {
+    pub sha: String,
+    pub status: LintStatus,
+    pub errors: Vec<String>,
+    /// Violations disabled by `Sumi-Disable` trailers.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub suppressed: Vec<Suppression>,
+    /// Violations accepted by the baseline.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub baselined: Vec<String>,
+    #[serde(flatten)]
+    pub commit: Option<ParsedCommit>,
+    /// Metadata of commits without a parsed commit to hold it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<CommitMetadata>,
+}
+
+impl RangeEntry {
+    pub fn linted(
+        commit: &GitCommit,
+        checked: CheckedCommit,
+        accepted: Option<&BTreeSet<String>>,
+    ) -> Self {
+        let CheckedCommit {
+            mut parsed_commit,
+            mut errors,
+            suppressions: suppressed,
+        } = checked;
+        parsed_commit.metadata = Some(commit.metadata.clone());
+        let baselined = accept_baselined(&mut errors, accepted);
+        RangeEntry {
+            sha: commit.sha.clone(),
+            status: if errors.is_empty() {
+                LintStatus::Passed
+            } else {
+                LintStatus::Failed
+            },
+            errors: errors.iter().map(|err| err.to_string()).collect(),
+            suppressed,
+            baselined: baselined.iter().map(|err| err.to_string()).collect(),
+            metadata: None,
+            commit: Some(parsed_commit),
+        }
+    }
+
+    pub fn skipped(commit: &GitCommit) -> Self {
+        RangeEntry {
+            sha: commit.sha.clone(),
+            status: LintStatus::Skipped,
+            errors: Vec::new(),
+            suppressed: Vec::new(),
+            baselined: Vec::new(),
+            commit: None,
+            metadata: Some(commit.metadata.clone()),
+        }
+    }
+}
+
+/// Displays the commits of a range as a single document: a JSON array,
+/// one JSON object per line, or a TOML array of tables named `commits`.
+pub fn display_commit_range(
+    entries: &[RangeEntry],
+    format: &ParsedCommitDisplayFormat,
+) -> Result<(), SumiError> {
+    match format {
+        ParsedCommitDisplayFormat::Json => {
+            let serialized = serde_json::to_string_pretty(entries).map_err(json_error)?;
+            println!("{serialized}");
+        }
+        ParsedCommitDisplayFormat::Ndjson => {
+            for entry in entries {
+                println!("{}", serialize_to_json_line(entry)?);
+            }
+        }
+        ParsedCommitDisplayFormat::Toml => {
+            #[derive(Serialize)]
+            struct RangeDocument<'a> {
+                commits: &'a [RangeEntry],
+            }
+            let serialized =
+                toml::to_string(&RangeDocument { commits: entries }).map_err(toml_error)?;
+            print!("{serialized}");
+        }
+        ParsedCommitDisplayFormat::Cli | ParsedCommitDisplayFormat::Table => {
+            for commit in entries.iter().filter_map(|entry| entry.commit.as_ref()) {
+                display_parsed_commit(commit, format)?;
+            }
+        }
+    }
+    Ok(())
+}
+
 fn display_parsed_commit_as_json(commit: &ParsedCommit) -> Result<(), SumiError> {
-    let serialized =
-        serde_json::to_string_pretty(commit).map_err(|err| SumiError::SerializationError {
-            format: "JSON".to_string(),
-            detail: err.to_string(),
-        })?;
+    let serialized = serde_json::to_string_pretty(commit).map_err(json_error)?;
     println!("{serialized}");
 
     Ok(())
 }
 
+fn serialize_to_json_line<T: Serialize>(value: &T) -> Result<String, SumiError> {
+    serde_json::to_string(value).map_err(json_error)
+}
+
+fn json_error(err: serde_json::Error) -> SumiError {
+    SumiError::SerializationError {
+        format: "JSON".to_string(),
+        detail: err.to_string(),
+    }
+}
+
+fn toml_error(err: toml::ser::Error) -> SumiError {
+    SumiError::SerializationError {
+        format: String::from("TOML"),
+        detail: err.to_string(),
+    }
+}
+
 fn display_parsed_commit_as_table(
     commit: &ParsedCommit,
     format: ParsedCommitDisplayFormat,
 ) -> Result<(), SumiError> {
+    let metadata = commit.metadata.as_ref();
     let fields = [
         ("Gitmoji", commit.gitmoji.as_ref().map(|g| g.join(", "))),
         ("Commit type", commit.commit_type.clone()),
         ("Scope", commit.scope.clone()),
+        (
+            "Scopes",
+            commit
+                .scopes
+                .as_ref()
+                .filter(|scopes| scopes.len() > 1)
+                .map(|scopes| scopes.join(", ")),
+        ),
         // "Description" is the only field that is guaranteed to be present.
         ("Description", Some(commit.description.clone())),
         ("Body", commit.body.clone()),
@@ -55,6 +188,27 @@ fn display_parsed_commit_as_table(
             "References",
             commit.references.as_ref().map(|r| r.join(", ")),
         ),
+        (
+            "Captures",
+            commit.captures.as_ref().map(|captures| {
+                captures
+                    .iter()
+                    .map(|(name, value)| format!("{name}: {value}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }),
+        ),
+        (
+            "Author",
+            metadata.map(|m| format!("{} <{}>", m.author_name, m.author_email)),
+        ),
+        ("Author date", metadata.map(|m| m.author_date.clone())),
+        (
+            "Committer",
+            metadata.map(|m| format!("{} <{}>", m.committer_name, m.committer_email)),
+        ),
+        ("Committer date", metadata.map(|m| m.committer_date.clone())),
+        ("Parents", metadata.map(|m| m.parents.to_string())),
     ];
 
     let mut builder = Builder::default();
@@ -90,9 +244,5 @@ fn display_parsed_commit_as_toml(commit: &ParsedCommit) -> Result<(), SumiError>
 }
 
 fn serialize_to_toml(commit: &ParsedCommit) -> Result<String, SumiError> {
-    let serialized = toml::to_string(&commit).map_err(|err| SumiError::SerializationError {
-        format: String::from("TOML"),
-        detail: err.to_string(),
-    })?;
-    Ok(serialized)
+    toml::to_string(&commit).map_err(toml_error)
 }
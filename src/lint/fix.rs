@@ -0,0 +1,77 @@
+use super::{scope_replacement, type_replacement};
+use crate::config::{Config, Convention};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Returns the header with invalid types and scopes replaced by their canonical names
+/// or unambiguous suggestions, or `None` if there's nothing to fix.
+pub fn fix_header(header: &str, config: &Config) -> Option<String> {
+    let captures = if let Some(header_format) = &config.compiled_header_format {
+        header_format.captures(header).ok()?
+    } else if matches!(
+        config.active_convention(),
+        Convention::Conventional | Convention::Angular
+    ) {
+        HEADER_PREFIX_REGEX.captures(header)?
+    } else {
+        // Other conventions don't have a 'type(scope):' prefix to fix.
+        return None;
+    };
+    // Templates may put the scope before the type, so fix them in the order they appear.
+    let fixes: [(&str, PartFix); 2] = [("type", fix_type), ("scope", fix_scope)];
+    let mut parts: Vec<_> = fixes
+        .into_iter()
+        .filter_map(|(name, fix)| captures.name(name).map(|part| (part, fix)))
+        .collect();
+    parts.sort_by_key(|(part, _)| part.start());
+    let mut fixed = String::new();
+    let mut last_end = 0;
+    for (part, fix) in parts {
+        fixed.push_str(&header[last_end..part.start()]);
+        fixed.push_str(&fix(part.as_str(), config));
+        last_end = part.end();
+    }
+    fixed.push_str(&header[last_end..]);
+    Some(fixed).filter(|fixed| fixed != header)
+}
+
+type PartFix = fn(&str, &Config) -> String;
+
+fn fix_type(commit_type: &str, config: &Config) -> String {
+    type_replacement(config, commit_type).unwrap_or_else(|| commit_type.to_string())
+}
+
+/// Replaces each invalid scope, keeping the delimiters and spacing between scopes.
+fn fix_scope(scope: &str, config: &Config) -> String {
+    let delimiters = match config.scope_delimiters.as_str() {
+        "" => ",",
+        delimiters => delimiters,
+    };
+    scope
+        .split_inclusive(|c| delimiters.contains(c))
+        .map(|piece| {
+            let name_start = piece.len() - piece.trim_start().len();
+            let name_end = piece
+                .trim_end_matches(|c| delimiters.contains(c))
+                .trim_end()
+                .len();
+            if name_start >= name_end {
+                return piece.to_string();
+            }
+            match scope_replacement(config, &piece[name_start..name_end]) {
+                Some(canonical) => {
+                    format!("{}{canonical}{}", &piece[..name_start], &piece[name_end..])
+                }
+                None => piece.to_string(),
+            }
+        })
+        .collect()
+}
+
+static HEADER_PREFIX_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    // Regex: optional leading emoji or :shortcodes:, then type, optional (scope), optional "!" and colon.
+    Regex::new(
+        r"^(?:(?::\w+:|[\p{Emoji_Presentation}\p{Extended_Pictographic}\u{200D}\u{FE0F}])\s*)*(?P<type>[^\s(:!]+)(?:\((?P<scope>[^)]*)\))?!?:",
+    )
+    .unwrap()
+});
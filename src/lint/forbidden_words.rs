@@ -0,0 +1,76 @@
+use super::constants::forbidden_words::DEFAULT_FORBIDDEN_WORDS;
+use super::split_and_trim_list;
+use crate::errors::SumiError;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// The built-in and configured forbidden words, compiled into a single regex
+/// with the replacement suggested for each word.
+#[derive(Debug, Clone)]
+pub(crate) struct ForbiddenWords {
+    regex: Regex,
+    replacements: HashMap<String, Option<String>>,
+}
+
+impl ForbiddenWords {
+    /// Compiles the built-in words with the `word` or `word=replacement` entries of `extra`.
+    pub fn compile(extra: &[String]) -> Result<Self, SumiError> {
+        let mut replacements: HashMap<String, Option<String>> = DEFAULT_FORBIDDEN_WORDS
+            .iter()
+            .map(|(word, replacement)| (word.to_string(), replacement.map(String::from)))
+            .collect();
+        for entry in split_and_trim_list(extra) {
+            let (word, replacement) = match entry.split_once('=') {
+                Some((word, replacement)) => (word.trim(), Some(replacement.trim().to_string())),
+                None => (entry.as_str(), None),
+            };
+            replacements.insert(normalise(word), replacement);
+        }
+
+        let mut words: Vec<&String> = replacements.keys().collect();
+        // Longer terms first, so that phrases win over the single words they contain.
+        words.sort_by_key(|word| std::cmp::Reverse(word.len()));
+        let alternation = words
+            .iter()
+            .map(|word| regex::escape(word).replace(' ', r"\s+"))
+            .collect::<Vec<String>>()
+            .join("|");
+        // All terms start and end with a word character, so word boundaries apply.
+        let regex = Regex::new(&format!(r"(?i)\b(?:{alternation})\b")).map_err(|err| {
+            SumiError::InvalidForbiddenWords {
+                reason: err.to_string(),
+            }
+        })?;
+        Ok(Self {
+            regex,
+            replacements,
+        })
+    }
+
+    /// Reports every forbidden word in the commit with its line and column.
+    pub fn find(&self, commit: &str) -> Vec<SumiError> {
+        let mut errors = Vec::new();
+        for (line_index, line) in commit.lines().enumerate() {
+            for found in self.regex.find_iter(line) {
+                errors.push(SumiError::ForbiddenWord {
+                    word: found.as_str().to_string(),
+                    line_number: line_index + 1,
+                    column: line[..found.start()].chars().count() + 1,
+                    replacement: self
+                        .replacements
+                        .get(&normalise(found.as_str()))
+                        .cloned()
+                        .flatten(),
+                });
+            }
+        }
+        errors
+    }
+}
+
+fn normalise(word: &str) -> String {
+    word.split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .to_lowercase()
+}
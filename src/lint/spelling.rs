@@ -0,0 +1,113 @@
+use super::constants::spelling_words::KNOWN_WORDS;
+use super::{find_code_lines, TRAILER_REGEX};
+use crate::config::find_project_dictionary;
+use crate::errors::SumiError;
+use crate::parser::REFERENCE_REGEX;
+use regex::Regex;
+use std::collections::HashSet;
+use std::sync::{LazyLock, OnceLock};
+
+const MAX_SUGGESTIONS: usize = 3;
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+static PROJECT_WORDS: OnceLock<HashSet<String>> = OnceLock::new();
+
+/// Words from the project dictionary, lowercased.
+/// Cached for the process lifetime. Empty if there's no project dictionary.
+fn project_words() -> &'static HashSet<String> {
+    PROJECT_WORDS.get_or_init(|| {
+        find_project_dictionary()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .map(|content| {
+                content
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                    .map(str::to_lowercase)
+                    .collect()
+            })
+            .unwrap_or_default()
+    })
+}
+
+/// Checks the spelling of the description (line 1) and the body lines of the commit.
+/// Code blocks, trailers, code spans, URLs, references and identifiers are ignored.
+pub fn validate_spelling(commit: &str, description: &str) -> Vec<SumiError> {
+    let mut errors = check_line(description, 1);
+    let body_lines: Vec<&str> = commit.lines().skip(1).collect();
+    let code_lines = find_code_lines(&body_lines);
+    for (index, line) in body_lines.iter().enumerate() {
+        if code_lines[index] || TRAILER_REGEX.is_match(line) {
+            continue;
+        }
+        errors.extend(check_line(line, index + 2));
+    }
+    errors
+}
+
+fn check_line(line: &str, line_number: usize) -> Vec<SumiError> {
+    let prose = IGNORED_SPANS_REGEX.replace_all(line, " ");
+    let prose = REFERENCE_REGEX.replace_all(&prose, " ");
+    prose
+        .split_whitespace()
+        .flat_map(|token| token.split('-'))
+        .filter_map(extract_word)
+        .filter(|word| !is_known_word(word))
+        .map(|word| SumiError::UnknownWord {
+            suggestions: suggest(&word.to_lowercase()),
+            word: word.to_string(),
+            line_number,
+        })
+        .collect()
+}
+
+/// Returns the word in the token, or `None` if the token isn't a plain word
+/// (e.g. identifiers like `snake_case`, `camelCase`, `ACRONYMS` or `v1.2`).
+fn extract_word(token: &str) -> Option<&str> {
+    let word = token.trim_matches(|c: char| !c.is_alphanumeric());
+    let word = word
+        .strip_suffix("'s")
+        .or_else(|| word.strip_suffix("’s"))
+        .unwrap_or(word);
+    if word.is_empty() || !word.chars().all(|c| c.is_alphabetic() || c == '\'') {
+        return None;
+    }
+    // Skip identifiers with uppercase letters after the first one.
+    if word.chars().skip(1).any(char::is_uppercase) {
+        return None;
+    }
+    Some(word)
+}
+
+fn is_known_word(word: &str) -> bool {
+    let lowercase = word.to_lowercase();
+    KNOWN_WORDS.contains(lowercase.as_str()) || project_words().contains(&lowercase)
+}
+
+/// Returns up to `MAX_SUGGESTIONS` known words closest to the given one.
+fn suggest(word: &str) -> Vec<String> {
+    let word_length = word.chars().count();
+    let mut candidates: Vec<(usize, &str)> = KNOWN_WORDS
+        .iter()
+        .copied()
+        .chain(project_words().iter().map(String::as_str))
+        .filter(|candidate| {
+            candidate.chars().count().abs_diff(word_length) <= MAX_SUGGESTION_DISTANCE
+        })
+        .map(|candidate| (strsim::damerau_levenshtein(word, candidate), candidate))
+        .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .collect();
+    candidates.sort_unstable();
+    candidates.dedup();
+    candidates
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate.to_string())
+        .collect()
+}
+
+static IGNORED_SPANS_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    // Regex: `code spans` | URLs | email addresses | :gitmoji: shortcodes.
+    Regex::new(r"`[^`]*`|\b[a-zA-Z][\w+.-]*://\S+|\bwww\.\S+|\S+@\S+|:\w+:")
+        .expect("Failed to compile regex")
+});
@@ -0,0 +1,40 @@
+/// Maximum edit distance for suggestions. Values up to 3 characters long allow a single edit.
+const MAX_DISTANCE: usize = 2;
+const MAX_SHORT_DISTANCE: usize = 1;
+const SHORT_VALUE_LENGTH: usize = 3;
+
+/// Returns the allowed values that best match `value`, ignoring case.
+/// More than one value means the suggestion is ambiguous.
+pub fn suggest(value: &str, allowed: &[String]) -> Vec<String> {
+    let value = value.to_lowercase();
+    let ranked: Vec<(usize, &String)> = allowed
+        .iter()
+        .filter_map(|candidate| Some((rank(&value, &candidate.to_lowercase())?, candidate)))
+        .collect();
+    let Some(best_rank) = ranked.iter().map(|(rank, _)| *rank).min() else {
+        return Vec::new();
+    };
+    ranked
+        .into_iter()
+        .filter(|(rank, _)| *rank == best_rank)
+        .map(|(_, candidate)| candidate.clone())
+        .collect()
+}
+
+/// Ranks how well a (lowercase) candidate matches the value; lower is better.
+/// Case-insensitive matches come first, then prefix matches, then matches by edit distance.
+fn rank(value: &str, candidate: &str) -> Option<usize> {
+    if value == candidate {
+        return Some(0);
+    }
+    if candidate.starts_with(value) || value.starts_with(candidate) {
+        return Some(1);
+    }
+    let max_distance = if value.chars().count() <= SHORT_VALUE_LENGTH {
+        MAX_SHORT_DISTANCE
+    } else {
+        MAX_DISTANCE
+    };
+    let distance = strsim::damerau_levenshtein(value, candidate);
+    (distance <= max_distance).then_some(2 + distance)
+}
@@ -0,0 +1,74 @@
+use super::{matches_wildcard, split_and_trim_list};
+use crate::config::{rule_names, Config};
+use crate::errors::SumiError;
+use regex::Regex;
+use serde::Serialize;
+use std::sync::LazyLock;
+
+/// A `Sumi-Disable: max-header-length, no_period` trailer. The key is case-insensitive, like git trailers.
+static SUPPRESSION_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^sumi-disable:(?P<rules>.*)$").unwrap());
+
+/// A rule violation disabled by a `Sumi-Disable` trailer.
+#[derive(Debug, Clone, Serialize)]
+pub struct Suppression {
+    pub rule: &'static str,
+    pub error: String,
+}
+
+/// Moves the errors of rules disabled by `Sumi-Disable` trailers out of `errors`.
+/// Unknown rules and rules in `unsuppressible_rules` are reported as errors instead.
+pub fn apply_suppressions(
+    commit: &str,
+    errors: &mut Vec<SumiError>,
+    config: &Config,
+) -> Vec<Suppression> {
+    let mut disabled = Vec::new();
+    let known_rules = rule_names();
+    for (index, line) in commit.lines().enumerate() {
+        let Some(captures) = SUPPRESSION_REGEX.captures(line) else {
+            continue;
+        };
+        let line_number = index + 1;
+        let written_rules = captures["rules"]
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|rule| !rule.is_empty());
+        for written in written_rules {
+            let normalised = written.to_lowercase().replace('-', "_");
+            let Some(rule) = known_rules.iter().find(|rule| **rule == normalised) else {
+                errors.push(SumiError::UnknownSuppressedRule {
+                    rule: written.to_string(),
+                    line_number,
+                });
+                continue;
+            };
+            if is_unsuppressible(rule, config) {
+                errors.push(SumiError::SuppressionForbidden {
+                    rule: written.to_string(),
+                    line_number,
+                });
+                continue;
+            }
+            disabled.push(*rule);
+        }
+    }
+
+    let mut suppressions = Vec::new();
+    errors.retain(|err| match err.rule() {
+        Some(rule) if disabled.contains(&rule) => {
+            suppressions.push(Suppression {
+                rule,
+                error: err.to_string(),
+            });
+            false
+        }
+        _ => true,
+    });
+    suppressions
+}
+
+fn is_unsuppressible(rule: &str, config: &Config) -> bool {
+    split_and_trim_list(&config.unsuppressible_rules)
+        .iter()
+        .any(|pattern| matches_wildcard(pattern, rule))
+}
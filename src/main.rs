@@ -1,6 +1,9 @@
-fn main() {
-    if let Err(e) = git_sumi::run() {
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let outcome = git_sumi::run();
+    if let Some(e) = outcome.error() {
         eprintln!("❌ Error: {e}");
-        std::process::exit(1);
     }
+    ExitCode::from(outcome.exit_code())
 }
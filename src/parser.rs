@@ -1,38 +1,207 @@
+mod angular_commit_parser;
 mod basic_parser;
+mod best_effort_parser;
+mod chromium_commit_parser;
 mod conventional_commit_parser;
+mod format_commit_parser;
+mod gitmoji_commit_parser;
+pub(crate) mod header_format;
+mod jira_commit_parser;
+mod kernel_commit_parser;
 pub mod parsed_commit;
 
-use crate::config::Config;
+use crate::config::{CompiledHeaderPattern, Config, Convention};
+use crate::git::GitCommit;
 use crate::SumiError;
 use regex::Regex;
+use std::collections::BTreeMap;
 use std::iter::Peekable;
 use std::sync::LazyLock;
 use std::vec::IntoIter;
 
+use self::angular_commit_parser::AngularCommitParser;
 use self::basic_parser::BasicCommitParser;
+use self::best_effort_parser::BestEffortParser;
+use self::chromium_commit_parser::ChromiumCommitParser;
 use self::conventional_commit_parser::ConventionalCommitParser;
+use self::format_commit_parser::FormatCommitParser;
+pub(crate) use self::gitmoji_commit_parser::gitmoji_name;
+use self::gitmoji_commit_parser::GitmojiCommitParser;
+use self::jira_commit_parser::JiraCommitParser;
+use self::kernel_commit_parser::KernelCommitParser;
 pub use self::parsed_commit::ParsedCommit;
 
-pub fn handle_parsing(
+/// Parses the commit following the configured convention.
+/// Parse failures are added to `errors`, with a best-effort parse returned so that
+/// the other rules still run.
+pub fn handle_parsing(commit: &str, config: &Config, errors: &mut Vec<SumiError>) -> ParsedCommit {
+    let mut parsed_commit = parse_commit(commit, config, errors);
+    parsed_commit.captures =
+        extract_captures(&parsed_commit.header, &config.compiled_header_patterns);
+    parsed_commit
+}
+
+fn parse_commit(commit: &str, config: &Config, errors: &mut Vec<SumiError>) -> ParsedCommit {
+    if commit.lines().next().is_none() {
+        errors.push(SumiError::EmptyCommitHeader);
+        return ParsedCommit::default();
+    }
+
+    if let Some(format) = &config.compiled_header_format {
+        let parser = FormatCommitParser { format };
+        return parser.parse(commit, config).unwrap_or_else(|e| {
+            errors.push(e);
+            parse_fallback(&BasicCommitParser, commit, config, errors)
+        });
+    }
+
+    let convention = config.active_convention();
+    let parser: &dyn CommitParser = match convention {
+        Convention::None => return parse_fallback(&BasicCommitParser, commit, config, errors),
+        Convention::Conventional => &ConventionalCommitParser,
+        Convention::Angular => &AngularCommitParser,
+        Convention::Kernel => &KernelCommitParser,
+        Convention::Gitmoji => &GitmojiCommitParser,
+        Convention::Jira => &JiraCommitParser,
+        Convention::Chromium => &ChromiumCommitParser,
+    };
+    match parser.parse(commit, config) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            errors.push(match convention {
+                Convention::Conventional => {
+                    let (line_number, column) = conventional_error_position(commit, config, &e);
+                    if line_number == 1 && has_empty_scope_at(commit, column) {
+                        SumiError::EmptyScope { column }
+                    } else {
+                        SumiError::FailedConventionalParse {
+                            reason: format!("{e}"),
+                            line_number,
+                            column,
+                        }
+                    }
+                }
+                _ => SumiError::FailedConventionParse {
+                    convention: convention.name().to_string(),
+                    reason: format!("{e}"),
+                },
+            });
+            let fallback: &dyn CommitParser = match convention {
+                Convention::Conventional | Convention::Angular => &BestEffortParser,
+                _ => &BasicCommitParser,
+            };
+            parse_fallback(fallback, commit, config, errors)
+        }
+    }
+}
+
+fn parse_fallback(
+    parser: &dyn CommitParser,
     commit: &str,
     config: &Config,
     errors: &mut Vec<SumiError>,
-) -> Result<ParsedCommit, SumiError> {
-    if config.conventional {
-        match ConventionalCommitParser.parse(commit, config) {
-            Ok(parsed) => Ok(parsed),
-            Err(e) => {
-                errors.push(SumiError::FailedConventionalParse {
-                    reason: format!("{e}"),
-                });
-                BasicCommitParser.parse(commit, config)
-            }
+) -> ParsedCommit {
+    parser.parse(commit, config).unwrap_or_else(|e| {
+        errors.push(e);
+        ParsedCommit::default()
+    })
+}
+
+/// Where a Conventional Commits parse error is, as a line number and a column (1-based).
+fn conventional_error_position(commit: &str, config: &Config, err: &SumiError) -> (usize, usize) {
+    let header = commit.lines().next().unwrap_or_default();
+    let header_sans_gitmoji = ConventionalCommitParser
+        .parse_and_remove_emoji(commit, config)
+        .ok()
+        .and_then(|(_, commit)| commit.lines().next().map(str::to_string))
+        .unwrap_or_else(|| header.to_string());
+    if let Some(offset) = conventional_header_error_offset(&header_sans_gitmoji) {
+        // Gitmoji are removed from the start of the header.
+        let gitmoji_width = header
+            .chars()
+            .count()
+            .saturating_sub(header_sans_gitmoji.chars().count());
+        return (
+            1,
+            gitmoji_width + header_sans_gitmoji[..offset].chars().count() + 1,
+        );
+    }
+
+    let kind = match err {
+        SumiError::InvalidConventionalCommit(err) => err.kind(),
+        SumiError::SeparateHeaderFromBody => git_conventional::ErrorKind::InvalidBody,
+        _ => return (1, 1),
+    };
+    match kind {
+        git_conventional::ErrorKind::InvalidBody => (2, 1),
+        git_conventional::ErrorKind::InvalidFooter => {
+            // The footers are the last paragraph.
+            let lines: Vec<&str> = commit.lines().collect();
+            let footer_start = lines
+                .iter()
+                .rposition(|line| line.trim().is_empty())
+                .map_or(1, |blank| blank + 2);
+            (footer_start.min(lines.len()), 1)
         }
-    } else {
-        BasicCommitParser.parse(commit, config)
+        _ => (1, 1),
     }
 }
 
+/// Whether the header has empty scope parentheses, like 'feat(): …', at the column (1-based).
+/// `git_conventional` reports them as a missing type.
+fn has_empty_scope_at(commit: &str, column: usize) -> bool {
+    let header = commit.lines().next().unwrap_or_default();
+    header.chars().skip(column - 1).take(2).eq("()".chars())
+}
+
+/// Byte offset where the header stops following 'type(scope)!: description', if it does.
+/// Mirrors the grammar of `git_conventional`, whose errors don't say where parsing failed.
+fn conventional_header_error_offset(header: &str) -> Option<usize> {
+    let type_length = header
+        .find(|c: char| matches!(c, '(' | ')' | ':' | '!') || c.is_whitespace())
+        .unwrap_or(header.len());
+    if type_length == 0 {
+        return Some(0);
+    }
+    let mut position = type_length;
+    if let Some(rest) = header[position..].strip_prefix('(') {
+        let scope_length = rest.find(['(', ')']).unwrap_or(rest.len());
+        if scope_length == 0 || !rest[scope_length..].starts_with(')') {
+            return Some(position);
+        }
+        position += scope_length + 2;
+    }
+    if header[position..].starts_with('!') {
+        position += 1;
+    }
+    if !header[position..].starts_with(':') {
+        return Some(position);
+    }
+    position += 1;
+    if header[position..].trim().is_empty() {
+        return Some(header.len());
+    }
+    None
+}
+
+/// Parses a commit history without linting it, returning each parsed commit with its SHA.
+/// Commits are parsed as Conventional Commits unless a convention is configured.
+/// Commits that don't follow the convention are parsed on a best-effort basis.
+pub fn parse_history(commits: &[GitCommit], config: &Config) -> Vec<(String, ParsedCommit)> {
+    let mut config = config.clone();
+    if config.active_convention() == Convention::None {
+        config.conventional = true;
+    }
+    commits
+        .iter()
+        .map(|commit| {
+            let mut parsed_commit = handle_parsing(&commit.message, &config, &mut Vec::new());
+            parsed_commit.metadata = Some(commit.metadata.clone());
+            (commit.sha.clone(), parsed_commit)
+        })
+        .collect()
+}
+
 trait CommitParser {
     fn parse(&self, commit: &str, config: &Config) -> Result<ParsedCommit, SumiError>;
 
@@ -67,9 +236,83 @@ trait CommitParser {
         // Return None if no references were found.
         Some(extracted_references).filter(|references| !references.is_empty())
     }
+
+    /// Splits everything after the header into the body and the footers.
+    /// The last paragraph holds the footers if all its lines are trailers, like 'Signed-off-by: …'.
+    fn extract_body_and_footers(&self, commit: &str) -> (Option<String>, Option<Vec<String>>) {
+        let rest = commit.lines().skip(1).collect::<Vec<_>>().join("\n");
+        let rest = rest.trim();
+        if rest.is_empty() {
+            return (None, None);
+        }
+        let (body, last_paragraph) = match rest.rsplit_once("\n\n") {
+            Some((body, last_paragraph)) => (Some(body.trim()), last_paragraph),
+            None => (None, rest),
+        };
+        if last_paragraph
+            .lines()
+            .all(|line| FOOTER_REGEX.is_match(line))
+        {
+            let footers = last_paragraph.lines().map(str::to_string).collect();
+            (body.map(str::to_string), Some(footers))
+        } else {
+            (Some(rest.to_string()), None)
+        }
+    }
+
+    /// Returns the description after `prefix_length` bytes of the header, failing if it's empty.
+    fn get_description(&self, header: &str, prefix_length: usize) -> Result<String, SumiError> {
+        let description = header[prefix_length..].trim();
+        if description.is_empty() {
+            return Err(SumiError::EmptyDescription);
+        }
+        Ok(description.to_string())
+    }
+}
+
+static FOOTER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    // Regex: "Token: value" or "Token #value", where tokens use '-' instead of spaces.
+    Regex::new(r"^(?:[\w-]+|BREAKING CHANGE)(?:: | #)\S").expect("Failed to compile regex")
+});
+
+/// Returns the named capture groups of the header patterns that matched the header.
+/// When several patterns capture the same name, the first one wins.
+fn extract_captures(
+    header: &str,
+    header_patterns: &[CompiledHeaderPattern],
+) -> Option<BTreeMap<String, String>> {
+    let mut captures = BTreeMap::new();
+    for re in header_patterns.iter().map(|p| &p.regex) {
+        let Some(caps) = re.captures(header) else {
+            continue;
+        };
+        for name in re.capture_names().flatten() {
+            if let Some(value) = caps.name(name) {
+                captures
+                    .entry(name.to_string())
+                    .or_insert_with(|| value.as_str().to_string());
+            }
+        }
+    }
+    Some(captures).filter(|captures| !captures.is_empty())
+}
+
+/// Splits a scope like "api, cli" into its individual scopes.
+/// Each character of `delimiters` is a delimiter. Defaults to ',' when empty.
+pub(crate) fn split_scopes(scope: &str, delimiters: &str) -> Vec<String> {
+    let delimiters = if delimiters.is_empty() {
+        ","
+    } else {
+        delimiters
+    };
+    scope
+        .split(|c| delimiters.contains(c))
+        .map(|scope| scope.trim().to_string())
+        .filter(|scope| !scope.is_empty())
+        .collect()
 }
 
-static REFERENCE_REGEX: LazyLock<Regex> =
+pub(crate) static REFERENCE_REGEX: LazyLock<Regex> =
     // Regex: #(issue/PR number) | 7-40 hexadecimal character SHAs.
     LazyLock::new(|| {
         Regex::new(r"(#\d+|\b[0-9a-f]{7,40}\b)").expect("Failed to compile regex")
@@ -92,7 +335,7 @@ fn extract_gitmoji(title: &str) -> Vec<String> {
     combine_adjacent_emojis(emojis)
 }
 
-static EMOJI_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+pub(crate) static EMOJI_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"(:\w+:)|[\p{Emoji_Presentation}\p{Extended_Pictographic}\u{200D}]")
         .expect("Failed to compile regex")
 });
@@ -0,0 +1,26 @@
+use super::conventional_commit_parser::ConventionalCommitParser;
+use super::{CommitParser, Config, ParsedCommit, SumiError};
+
+/// Commit types of the Angular commit message guidelines.
+/// See https://github.com/angular/angular/blob/main/contributing-docs/commit-message-guidelines.md
+pub(crate) const ANGULAR_TYPES: [&str; 8] = [
+    "build", "ci", "docs", "feat", "fix", "perf", "refactor", "test",
+];
+
+/// Conventional Commits restricted to the Angular commit types.
+pub struct AngularCommitParser;
+
+impl CommitParser for AngularCommitParser {
+    fn parse(&self, commit: &str, config: &Config) -> Result<ParsedCommit, SumiError> {
+        let parsed_commit = ConventionalCommitParser.parse(commit, config)?;
+        let commit_type = parsed_commit.commit_type.as_deref().unwrap_or_default();
+        if !ANGULAR_TYPES.contains(&commit_type) {
+            return Err(SumiError::InvalidCommitType {
+                type_found: commit_type.to_string(),
+                allowed_types: ANGULAR_TYPES.join(", "),
+                suggestions: Vec::new(),
+            });
+        }
+        Ok(parsed_commit)
+    }
+}
@@ -0,0 +1,53 @@
+use super::basic_parser::BasicCommitParser;
+use super::{split_scopes, CommitParser, Config, ParsedCommit, SumiError};
+use regex::Regex;
+use std::sync::LazyLock;
+
+static LENIENT_HEADER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    // Regex: "type(scope)!: description", tolerating spaces around each part.
+    Regex::new(
+        r"^(?P<type>\w[\w-]*)\s*(?:\((?P<scope>[^()]*)\))?\s*(?P<breaking>!)?\s*:\s*(?P<description>\S.*)$",
+    )
+    .expect("Failed to compile regex")
+});
+
+/// Recovers what it can from commits that don't parse as Conventional Commits,
+/// like the type of 'feat (api):add export', so that the other rules can still check them.
+/// Headers that don't look like 'type: description' are parsed as plain messages.
+pub struct BestEffortParser;
+
+impl CommitParser for BestEffortParser {
+    fn parse(&self, commit: &str, config: &Config) -> Result<ParsedCommit, SumiError> {
+        let mut parsed_commit = BasicCommitParser.parse(commit, config)?;
+        let (_, commit) = self.parse_and_remove_emoji(commit, config)?;
+        let header = self.get_commit_header(&commit)?;
+        let Some(caps) = LENIENT_HEADER_REGEX.captures(&header) else {
+            return Ok(parsed_commit);
+        };
+
+        let (body, footers) = self.extract_body_and_footers(&commit);
+        let breaking_footer = footers.iter().flatten().find_map(|footer| {
+            footer
+                .strip_prefix("BREAKING CHANGE: ")
+                .or_else(|| footer.strip_prefix("BREAKING-CHANGE: "))
+        });
+        let description = caps["description"].trim_end().to_string();
+        let has_bang = caps.name("breaking").is_some();
+        let scope = caps.name("scope").map(|scope| scope.as_str().to_string());
+
+        parsed_commit.commit_type = Some(caps["type"].to_string());
+        parsed_commit.scopes = scope
+            .as_deref()
+            .map(|scope| split_scopes(scope, &config.scope_delimiters));
+        parsed_commit.scope = scope;
+        parsed_commit.is_breaking = Some(has_bang || breaking_footer.is_some());
+        parsed_commit.breaking_description = match breaking_footer {
+            Some(footer) => Some(footer.to_string()),
+            None => has_bang.then(|| description.clone()),
+        };
+        parsed_commit.description = description;
+        parsed_commit.body = body;
+        parsed_commit.footers = footers;
+        Ok(parsed_commit)
+    }
+}
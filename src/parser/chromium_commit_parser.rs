@@ -0,0 +1,45 @@
+use super::{CommitParser, Config, ParsedCommit, SumiError};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Chromium style component tags: `[component] description`, or `[a][b] description`.
+/// The components fill in the scope.
+pub struct ChromiumCommitParser;
+
+static COMPONENTS_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    // Regex: one or more "[component]" tags, optionally separated by whitespace.
+    Regex::new(r"^(?:\[[^\[\]]+\]\s*)+").expect("Failed to compile regex")
+});
+
+static COMPONENT_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\[([^\[\]]+)\]").expect("Failed to compile regex"));
+
+impl CommitParser for ChromiumCommitParser {
+    fn parse(&self, commit: &str, config: &Config) -> Result<ParsedCommit, SumiError> {
+        let header = self.get_commit_header(commit)?;
+        let (gitmoji, commit) = self.parse_and_remove_emoji(commit, config)?;
+        let header_sans_gitmoji = self.get_commit_header(&commit)?;
+        let tags = COMPONENTS_REGEX.find(&header_sans_gitmoji).ok_or_else(|| {
+            SumiError::HeaderConventionMismatch {
+                format: "[component] description".to_string(),
+            }
+        })?;
+        let description = self.get_description(&header_sans_gitmoji, tags.end())?;
+        let components: Vec<String> = COMPONENT_REGEX
+            .captures_iter(tags.as_str())
+            .map(|caps| caps[1].trim().to_string())
+            .collect();
+        let (body, footers) = self.extract_body_and_footers(&commit);
+        Ok(ParsedCommit {
+            header,
+            gitmoji,
+            scope: Some(components.join(", ")),
+            scopes: Some(components),
+            description,
+            body,
+            footers,
+            references: self.extract_references(&commit),
+            ..Default::default()
+        })
+    }
+}
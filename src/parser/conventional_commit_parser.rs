@@ -1,4 +1,4 @@
-use super::{CommitParser, Config, ParsedCommit, SumiError};
+use super::{split_scopes, CommitParser, Config, ParsedCommit, SumiError};
 
 pub struct ConventionalCommitParser;
 
@@ -32,6 +32,9 @@ impl CommitParser for ConventionalCommitParser {
             gitmoji,
             commit_type: Some(conventional_commit.type_().to_string()),
             scope: conventional_commit.scope().map(|s| s.to_string()),
+            scopes: conventional_commit
+                .scope()
+                .map(|s| split_scopes(&s, &config.scope_delimiters)),
             description: conventional_commit.description().to_string(),
             body: conventional_commit.body().map(|s| s.to_string()),
             footers: Some(footers).filter(|f| !f.is_empty()),
@@ -40,6 +43,7 @@ impl CommitParser for ConventionalCommitParser {
                 .breaking_description()
                 .map(|s| s.to_string()),
             references,
+            ..Default::default()
         };
 
         Ok(parsed_commit)
@@ -0,0 +1,42 @@
+use super::header_format::HeaderFormat;
+use super::{split_scopes, CommitParser, Config, ParsedCommit, SumiError};
+
+/// Parses headers following a `header_format` template.
+/// Each placeholder fills in its field; the ticket is added to the references.
+pub struct FormatCommitParser<'a> {
+    pub format: &'a HeaderFormat,
+}
+
+impl CommitParser for FormatCommitParser<'_> {
+    fn parse(&self, commit: &str, config: &Config) -> Result<ParsedCommit, SumiError> {
+        let header = self.get_commit_header(commit)?;
+        let captures = self.format.captures(&header)?;
+        let capture = |name: &str| {
+            captures
+                .name(name)
+                .map(|m| m.as_str().trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let scope = capture("scope");
+        let (body, footers) = self.extract_body_and_footers(commit);
+        let mut references = self.extract_references(commit).unwrap_or_default();
+        if let Some(ticket) = capture("ticket").filter(|ticket| !references.contains(ticket)) {
+            references.insert(0, ticket);
+        }
+        Ok(ParsedCommit {
+            gitmoji: capture("gitmoji").map(|gitmoji| vec![gitmoji]),
+            commit_type: capture("type"),
+            scopes: scope
+                .as_ref()
+                .map(|scope| split_scopes(scope, &config.scope_delimiters)),
+            scope,
+            description: capture("description").unwrap_or_default(),
+            is_breaking: captures.name("breaking").map(|m| !m.as_str().is_empty()),
+            body,
+            footers,
+            references: Some(references).filter(|references| !references.is_empty()),
+            header,
+            ..Default::default()
+        })
+    }
+}
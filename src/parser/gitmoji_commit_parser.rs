@@ -0,0 +1,43 @@
+use super::{parse_gitmoji, remove_gitmoji, CommitParser, Config, ParsedCommit, SumiError};
+use crate::lint::constants::gitmoji::GITMOJI_NAMES;
+
+/// Gitmoji-only headers: `✨ description` or `:sparkles: description`.
+/// The gitmoji name, like 'sparkles', fills in the type.
+pub struct GitmojiCommitParser;
+
+impl CommitParser for GitmojiCommitParser {
+    fn parse(&self, commit: &str, _config: &Config) -> Result<ParsedCommit, SumiError> {
+        let header = self.get_commit_header(commit)?;
+        // The gitmoji is the type, so it's parsed regardless of the `gitmoji` rule.
+        let emojis = parse_gitmoji(&header)
+            .filter(|emojis| header.trim_start().starts_with(emojis[0].as_str()))
+            .ok_or_else(|| SumiError::HeaderConventionMismatch {
+                format: "<gitmoji> description".to_string(),
+            })?;
+        let commit = remove_gitmoji(commit, &emojis);
+        let description = self.get_description(&self.get_commit_header(&commit)?, 0)?;
+        let (body, footers) = self.extract_body_and_footers(&commit);
+        Ok(ParsedCommit {
+            header,
+            commit_type: Some(gitmoji_name(&emojis[0])),
+            gitmoji: Some(emojis),
+            description,
+            body,
+            footers,
+            references: self.extract_references(&commit),
+            ..Default::default()
+        })
+    }
+}
+
+/// Returns the name of a gitmoji, like 'sparkles' for both "✨" and ":sparkles:".
+/// Unknown emojis are their own name.
+pub(crate) fn gitmoji_name(emoji: &str) -> String {
+    if let Some(shortcode) = emoji.strip_prefix(':').and_then(|e| e.strip_suffix(':')) {
+        return shortcode.to_string();
+    }
+    let emoji = emoji.replace('\u{fe0f}', "");
+    GITMOJI_NAMES
+        .get(emoji.as_str())
+        .map_or(emoji.clone(), |name| name.to_string())
+}
@@ -0,0 +1,220 @@
+use crate::SumiError;
+use regex::{Captures, Regex};
+
+/// A `header_format` template, like "{gitmoji} {type}({scope}): {description}",
+/// compiled into a grammar that reports where a header stops matching.
+#[derive(Debug, Clone)]
+pub(crate) struct HeaderFormat {
+    template: String,
+    segments: Vec<Segment>,
+    /// `prefixes[i]` matches the header up to and including `segments[i]`.
+    prefixes: Vec<Regex>,
+    full: Regex,
+}
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Literal(String),
+    Placeholder(Placeholder),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Placeholder {
+    Gitmoji,
+    Type,
+    Scope,
+    Breaking,
+    Description,
+    Ticket,
+}
+
+const PLACEHOLDERS: [Placeholder; 6] = [
+    Placeholder::Gitmoji,
+    Placeholder::Type,
+    Placeholder::Scope,
+    Placeholder::Breaking,
+    Placeholder::Description,
+    Placeholder::Ticket,
+];
+
+impl Placeholder {
+    /// Name in the template, and of the capture group.
+    fn name(self) -> &'static str {
+        match self {
+            Placeholder::Gitmoji => "gitmoji",
+            Placeholder::Type => "type",
+            Placeholder::Scope => "scope",
+            Placeholder::Breaking => "breaking",
+            Placeholder::Description => "description",
+            Placeholder::Ticket => "ticket",
+        }
+    }
+
+    /// Regex for the placeholder. Lazy placeholders leave as much as possible to the next segments.
+    fn pattern(self, lazy: bool) -> String {
+        let quantifier = if lazy { "+?" } else { "+" };
+        let pattern = match self {
+            Placeholder::Gitmoji => format!(
+                r"(?::\w+:|[\p{{Emoji_Presentation}}\p{{Extended_Pictographic}}\u{{200D}}\u{{FE0F}}]){quantifier}"
+            ),
+            Placeholder::Type => format!(r"[\w-]{quantifier}"),
+            Placeholder::Scope => format!(r"[^()\[\]]{quantifier}"),
+            Placeholder::Breaking => "!?".to_string(),
+            Placeholder::Description => format!(r".{quantifier}"),
+            Placeholder::Ticket => r"[A-Z][A-Z0-9_]+-\d+|#\d+".to_string(),
+        };
+        format!("(?P<{}>{pattern})", self.name())
+    }
+
+    fn expected(self) -> &'static str {
+        match self {
+            Placeholder::Gitmoji => "a gitmoji",
+            Placeholder::Type => "a type, like 'feat'",
+            Placeholder::Scope => "a scope",
+            Placeholder::Breaking => "'!'",
+            Placeholder::Description => "a description",
+            Placeholder::Ticket => "a ticket, like 'PROJ-123' or '#123'",
+        }
+    }
+}
+
+impl Segment {
+    fn pattern(&self, lazy: bool) -> String {
+        match self {
+            Segment::Literal(text) => regex::escape(text),
+            Segment::Placeholder(placeholder) => placeholder.pattern(lazy),
+        }
+    }
+
+    fn expected(&self) -> String {
+        match self {
+            Segment::Literal(text) => format!("'{text}'"),
+            Segment::Placeholder(placeholder) => placeholder.expected().to_string(),
+        }
+    }
+}
+
+impl HeaderFormat {
+    pub fn compile(template: &str) -> Result<Self, SumiError> {
+        let segments = parse_template(template)?;
+        let build = |segments: &[Segment]| {
+            let last = segments.len() - 1;
+            segments
+                .iter()
+                .enumerate()
+                .map(|(i, segment)| segment.pattern(i != last))
+                .collect::<String>()
+        };
+        let compile = |pattern: String| {
+            Regex::new(&pattern).map_err(|_| SumiError::InvalidHeaderFormat {
+                format: template.to_string(),
+                reason: "it doesn't compile to a valid regex".to_string(),
+            })
+        };
+        let prefixes = (1..=segments.len())
+            .map(|end| compile(format!("^{}", build(&segments[..end]))))
+            .collect::<Result<Vec<_>, _>>()?;
+        let full = compile(format!("^{}$", build(&segments)))?;
+        Ok(HeaderFormat {
+            template: template.to_string(),
+            segments,
+            prefixes,
+            full,
+        })
+    }
+
+    /// Matches the header, returning the placeholders' captures.
+    /// On mismatch, the error names the first segment that failed and its column.
+    pub fn captures<'h>(&self, header: &'h str) -> Result<Captures<'h>, SumiError> {
+        if let Some(captures) = self.full.captures(header) {
+            return Ok(captures);
+        }
+        let mut matched_end = 0;
+        for (segment, prefix) in self.segments.iter().zip(&self.prefixes) {
+            match prefix.find(header) {
+                Some(matched) => matched_end = matched.end(),
+                None => return Err(self.mismatch(header, matched_end, segment.expected())),
+            }
+        }
+        Err(self.mismatch(header, matched_end, "the end of the header".to_string()))
+    }
+
+    fn mismatch(&self, header: &str, position: usize, expected: String) -> SumiError {
+        let rest = &header[position..];
+        SumiError::HeaderFormatMismatch {
+            format: self.template.clone(),
+            expected,
+            column: header[..position].chars().count() + 1,
+            found: if rest.is_empty() {
+                "the end of the header".to_string()
+            } else {
+                format!("'{rest}'")
+            },
+        }
+    }
+}
+
+/// Splits a template into literals and `{placeholders}`. Use `{{` and `}}` for literal braces.
+fn parse_template(template: &str) -> Result<Vec<Segment>, SumiError> {
+    let invalid = |reason: String| SumiError::InvalidHeaderFormat {
+        format: template.to_string(),
+        reason,
+    };
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' | '}' if chars.peek() == Some(&c) => {
+                chars.next();
+                literal.push(c);
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err(invalid(format!("'{{{name}' is never closed"))),
+                    }
+                }
+                let placeholder = PLACEHOLDERS
+                    .into_iter()
+                    .find(|placeholder| placeholder.name() == name)
+                    .ok_or_else(|| {
+                        let names: Vec<_> = PLACEHOLDERS.iter().map(|p| p.name()).collect();
+                        invalid(format!(
+                            "unknown placeholder '{{{name}}}'. Available placeholders: {}",
+                            names.join(", ")
+                        ))
+                    })?;
+                if segments
+                    .iter()
+                    .any(|segment| matches!(segment, Segment::Placeholder(p) if *p == placeholder))
+                {
+                    return Err(invalid(format!("'{{{name}}}' is used more than once")));
+                }
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                }
+                segments.push(Segment::Placeholder(placeholder));
+            }
+            '}' => {
+                return Err(invalid(
+                    "unmatched '}'. Use '}}' for a literal brace".to_string(),
+                ))
+            }
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+    if !segments
+        .iter()
+        .any(|segment| matches!(segment, Segment::Placeholder(Placeholder::Description)))
+    {
+        return Err(invalid("it must contain '{description}'".to_string()));
+    }
+    Ok(segments)
+}
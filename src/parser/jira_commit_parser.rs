@@ -0,0 +1,42 @@
+use super::{CommitParser, Config, ParsedCommit, SumiError};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Jira-prefixed headers: `PROJ-123: description`.
+/// The project key fills in the type and the issue key fills in the scope.
+pub struct JiraCommitParser;
+
+static ISSUE_KEY_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    // Regex: uppercase project key, a hyphen and the issue number, followed by a colon.
+    Regex::new(r"^(?P<key>(?P<project>[A-Z][A-Z0-9_]+)-\d+):").expect("Failed to compile regex")
+});
+
+impl CommitParser for JiraCommitParser {
+    fn parse(&self, commit: &str, config: &Config) -> Result<ParsedCommit, SumiError> {
+        let header = self.get_commit_header(commit)?;
+        let (gitmoji, commit) = self.parse_and_remove_emoji(commit, config)?;
+        let header_sans_gitmoji = self.get_commit_header(&commit)?;
+        let caps = ISSUE_KEY_REGEX
+            .captures(&header_sans_gitmoji)
+            .ok_or_else(|| SumiError::HeaderConventionMismatch {
+                format: "PROJ-123: description".to_string(),
+            })?;
+        let description = self.get_description(&header_sans_gitmoji, caps[0].len())?;
+        let key = caps["key"].to_string();
+        let (body, footers) = self.extract_body_and_footers(&commit);
+        let mut references = self.extract_references(&commit).unwrap_or_default();
+        references.insert(0, key.clone());
+        Ok(ParsedCommit {
+            header,
+            gitmoji,
+            commit_type: Some(caps["project"].to_string()),
+            scopes: Some(vec![key.clone()]),
+            scope: Some(key),
+            description,
+            body,
+            footers,
+            references: Some(references),
+            ..Default::default()
+        })
+    }
+}
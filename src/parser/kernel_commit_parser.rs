@@ -0,0 +1,44 @@
+use super::{CommitParser, Config, ParsedCommit, SumiError};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Linux kernel style: `subsystem: description`, with nested subsystems like `net: ipv4: …`.
+/// The subsystems fill in the scope.
+pub struct KernelCommitParser;
+
+static SUBSYSTEMS_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    // Regex: one or more "subsystem: " prefixes. Subsystems can't contain whitespace.
+    Regex::new(r"^(?:[^\s:]+: )+").expect("Failed to compile regex")
+});
+
+impl CommitParser for KernelCommitParser {
+    fn parse(&self, commit: &str, config: &Config) -> Result<ParsedCommit, SumiError> {
+        let header = self.get_commit_header(commit)?;
+        let (gitmoji, commit) = self.parse_and_remove_emoji(commit, config)?;
+        let header_sans_gitmoji = self.get_commit_header(&commit)?;
+        let prefix = SUBSYSTEMS_REGEX.find(&header_sans_gitmoji).ok_or_else(|| {
+            SumiError::HeaderConventionMismatch {
+                format: "subsystem: description".to_string(),
+            }
+        })?;
+        let description = self.get_description(&header_sans_gitmoji, prefix.end())?;
+        let subsystems: Vec<String> = prefix
+            .as_str()
+            .split(": ")
+            .filter(|subsystem| !subsystem.is_empty())
+            .map(str::to_string)
+            .collect();
+        let (body, footers) = self.extract_body_and_footers(&commit);
+        Ok(ParsedCommit {
+            header,
+            gitmoji,
+            scope: Some(subsystems.join(": ")),
+            scopes: Some(subsystems),
+            description,
+            body,
+            footers,
+            references: self.extract_references(&commit),
+            ..Default::default()
+        })
+    }
+}
@@ -1,4 +1,6 @@
+use crate::git::CommitMetadata;
 use serde::Serialize;
+use std::collections::BTreeMap;
 
 #[derive(Debug, Default, Serialize)]
 pub struct ParsedCommit {
@@ -14,6 +16,9 @@ pub struct ParsedCommit {
     pub commit_type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scope: Option<String>,
+    /// The individual scopes, split on the `scope_delimiters`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<String>>,
     pub description: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body: Option<String>,
@@ -25,6 +30,12 @@ pub struct ParsedCommit {
     pub breaking_description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub references: Option<Vec<String>>,
+    /// Named capture groups of the `header_pattern` that matched.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub captures: Option<BTreeMap<String, String>>,
+    /// Author, committer and parents, for commits read from the repository.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<CommitMetadata>,
 }
 
 fn serialize_gitmoji<S>(gitmoji: &Option<Vec<String>>, serializer: S) -> Result<S::Ok, S::Error>
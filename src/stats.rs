@@ -0,0 +1,367 @@
+use crate::config::{active_rule_names, Config};
+use crate::errors::SumiError;
+use crate::git::GitCommit;
+use crate::lint::{lint_silently, messages_to_lint};
+use crate::parser::{gitmoji_name, parse_history};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use tabled::{builder::Builder, settings::Style};
+
+/// Number of violations listed as the most common.
+const TOP_VIOLATIONS: usize = 10;
+
+/// Output format of `git-sumi stats`.
+#[derive(Debug, Clone, Copy, ValueEnum, Default)]
+pub enum StatsFormat {
+    #[default]
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Stats {
+    pub commits: usize,
+    pub compliant_commits: usize,
+    pub compliance_rate: f64,
+    pub breaking_changes: usize,
+    pub average_header_length: f64,
+    pub rules: Vec<RuleStats>,
+    /// The most common error messages.
+    pub violations: Vec<Count>,
+    pub types: Vec<Count>,
+    pub scopes: Vec<Count>,
+    pub gitmoji: Vec<Count>,
+    pub authors: Vec<AuthorStats>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RuleStats {
+    pub rule: String,
+    /// Commits that break the rule.
+    pub violations: usize,
+    pub compliance_rate: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Count {
+    pub name: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuthorStats {
+    pub author: String,
+    pub commits: usize,
+    pub compliant_commits: usize,
+    pub compliance_rate: f64,
+}
+
+/// Lints the commits and aggregates the results.
+/// As in range linting, each author's configuration applies and skipped commits are left out.
+pub fn collect_stats(commits: &[GitCommit], config: &Config) -> Stats {
+    let commits: Vec<GitCommit> = commits
+        .iter()
+        .filter(|commit| !config.skips_commit(&commit.metadata))
+        .cloned()
+        .collect();
+    let total = commits.len();
+    let mut compliant_commits = 0;
+    let mut header_lengths = 0;
+    let mut rule_violations: HashMap<&'static str, usize> = HashMap::new();
+    let mut violations: HashMap<String, usize> = HashMap::new();
+    // Commits and compliant commits, by author.
+    let mut author_counts: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+
+    for commit in &commits {
+        header_lengths += commit.message.lines().next().unwrap_or("").chars().count();
+        let config = config.for_commit(&commit.metadata);
+        let errors: Vec<SumiError> = messages_to_lint(commit, config)
+            .into_iter()
+            .flat_map(|message| lint_silently(message, config).errors)
+            .collect();
+        let is_compliant = errors.is_empty();
+        if is_compliant {
+            compliant_commits += 1;
+        }
+
+        let mut rules: Vec<&'static str> = errors.iter().filter_map(|err| err.rule()).collect();
+        rules.sort_unstable();
+        rules.dedup();
+        for rule in rules {
+            *rule_violations.entry(rule).or_default() += 1;
+        }
+        for err in &errors {
+            *violations.entry(err.to_string()).or_default() += 1;
+        }
+
+        let counts = author_counts
+            .entry(commit.metadata.author_name.clone())
+            .or_default();
+        counts.0 += 1;
+        if is_compliant {
+            counts.1 += 1;
+        }
+    }
+
+    let mut rule_names = active_rule_names(config);
+    let mut violated_rules: Vec<&'static str> = rule_violations
+        .keys()
+        .copied()
+        .filter(|rule| !rule_names.contains(rule))
+        .collect();
+    violated_rules.sort_unstable();
+    rule_names.extend(violated_rules);
+    let rules = rule_names
+        .into_iter()
+        .map(|rule| {
+            let violations = rule_violations.get(rule).copied().unwrap_or_default();
+            RuleStats {
+                rule: rule.to_string(),
+                violations,
+                compliance_rate: rate(total - violations, total),
+            }
+        })
+        .collect();
+
+    let mut types = HashMap::new();
+    let mut scopes = HashMap::new();
+    let mut gitmoji = HashMap::new();
+    let mut breaking_changes = 0;
+    for (_, commit) in parse_history(&commits, config) {
+        if let Some(commit_type) = commit.commit_type {
+            *types.entry(commit_type).or_default() += 1;
+        }
+        for scope in commit.scopes.into_iter().flatten() {
+            *scopes.entry(scope).or_default() += 1;
+        }
+        for emoji in commit.gitmoji.iter().flatten() {
+            *gitmoji.entry(gitmoji_name(emoji)).or_default() += 1;
+        }
+        if commit.is_breaking == Some(true) {
+            breaking_changes += 1;
+        }
+    }
+
+    let mut violations = sorted_counts(violations);
+    violations.truncate(TOP_VIOLATIONS);
+
+    Stats {
+        commits: total,
+        compliant_commits,
+        compliance_rate: rate(compliant_commits, total),
+        breaking_changes,
+        average_header_length: round(header_lengths as f64 / total.max(1) as f64),
+        rules,
+        violations,
+        types: sorted_counts(types),
+        scopes: sorted_counts(scopes),
+        gitmoji: sorted_counts(gitmoji),
+        authors: author_counts
+            .into_iter()
+            .map(|(author, (commits, compliant_commits))| AuthorStats {
+                author,
+                commits,
+                compliant_commits,
+                compliance_rate: rate(compliant_commits, commits),
+            })
+            .collect(),
+    }
+}
+
+fn rate(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        return 1.0;
+    }
+    round(part as f64 / total as f64)
+}
+
+fn round(value: f64) -> f64 {
+    (value * 1000.0).round() / 1000.0
+}
+
+/// Most common first, then by name.
+fn sorted_counts(counts: HashMap<String, usize>) -> Vec<Count> {
+    let mut counts: Vec<Count> = counts
+        .into_iter()
+        .map(|(name, count)| Count { name, count })
+        .collect();
+    counts.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+    counts
+}
+
+pub fn render_stats(stats: &Stats, format: StatsFormat) -> Result<String, SumiError> {
+    match format {
+        StatsFormat::Table => Ok(render_tables(stats)),
+        StatsFormat::Json => {
+            serde_json::to_string_pretty(stats).map_err(|err| SumiError::SerializationError {
+                format: "JSON".to_string(),
+                detail: err.to_string(),
+            })
+        }
+        StatsFormat::Csv => Ok(render_csv(stats)),
+    }
+}
+
+fn percentage(rate: f64) -> String {
+    format!("{:.1}%", rate * 100.0)
+}
+
+fn render_tables(stats: &Stats) -> String {
+    let mut tables = Vec::new();
+    let mut push_table = |header: &[&str], rows: Vec<Vec<String>>| {
+        if rows.is_empty() {
+            return;
+        }
+        let mut builder = Builder::default();
+        builder.push_record(header.iter().map(|h| h.to_string()));
+        for row in rows {
+            builder.push_record(row);
+        }
+        let mut table = builder.build();
+        table.with(Style::modern());
+        tables.push(table.to_string());
+    };
+
+    push_table(
+        &["Metric", "Value"],
+        vec![
+            vec!["Commits".to_string(), stats.commits.to_string()],
+            vec![
+                "Compliant commits".to_string(),
+                format!(
+                    "{} ({})",
+                    stats.compliant_commits,
+                    percentage(stats.compliance_rate)
+                ),
+            ],
+            vec![
+                "Breaking changes".to_string(),
+                stats.breaking_changes.to_string(),
+            ],
+            vec![
+                "Average header length".to_string(),
+                format!("{:.1}", stats.average_header_length),
+            ],
+        ],
+    );
+    push_table(
+        &["Rule", "Violations", "Compliance"],
+        stats
+            .rules
+            .iter()
+            .map(|rule| {
+                vec![
+                    rule.rule.clone(),
+                    rule.violations.to_string(),
+                    percentage(rule.compliance_rate),
+                ]
+            })
+            .collect(),
+    );
+    push_table(
+        &["Most common violations", "Count"],
+        count_rows(&stats.violations),
+    );
+    push_table(&["Type", "Commits"], count_rows(&stats.types));
+    push_table(&["Scope", "Commits"], count_rows(&stats.scopes));
+    push_table(&["Gitmoji", "Commits"], count_rows(&stats.gitmoji));
+    push_table(
+        &["Author", "Commits", "Compliance"],
+        stats
+            .authors
+            .iter()
+            .map(|author| {
+                vec![
+                    author.author.clone(),
+                    author.commits.to_string(),
+                    percentage(author.compliance_rate),
+                ]
+            })
+            .collect(),
+    );
+
+    tables.join("\n\n")
+}
+
+fn count_rows(counts: &[Count]) -> Vec<Vec<String>> {
+    counts
+        .iter()
+        .map(|count| vec![count.name.clone(), count.count.to_string()])
+        .collect()
+}
+
+/// One row per value, as `section,name,value,compliance_rate`.
+fn render_csv(stats: &Stats) -> String {
+    let mut rows = vec!["section,name,value,compliance_rate".to_string()];
+    let mut push_row = |section: &str, name: &str, value: String, rate: Option<f64>| {
+        rows.push(format!(
+            "{section},{},{value},{}",
+            csv_field(name),
+            rate.map_or_else(String::new, |rate| rate.to_string())
+        ));
+    };
+
+    push_row(
+        "summary",
+        "commits",
+        stats.commits.to_string(),
+        Some(stats.compliance_rate),
+    );
+    push_row(
+        "summary",
+        "compliant_commits",
+        stats.compliant_commits.to_string(),
+        None,
+    );
+    push_row(
+        "summary",
+        "breaking_changes",
+        stats.breaking_changes.to_string(),
+        None,
+    );
+    push_row(
+        "summary",
+        "average_header_length",
+        stats.average_header_length.to_string(),
+        None,
+    );
+    for rule in &stats.rules {
+        push_row(
+            "rule",
+            &rule.rule,
+            rule.violations.to_string(),
+            Some(rule.compliance_rate),
+        );
+    }
+    for (section, counts) in [
+        ("violation", &stats.violations),
+        ("type", &stats.types),
+        ("scope", &stats.scopes),
+        ("gitmoji", &stats.gitmoji),
+    ] {
+        for count in counts {
+            push_row(section, &count.name, count.count.to_string(), None);
+        }
+    }
+    for author in &stats.authors {
+        push_row(
+            "author",
+            &author.author,
+            author.commits.to_string(),
+            Some(author.compliance_rate),
+        );
+    }
+
+    rows.join("\n")
+}
+
+/// Quotes fields with commas, quotes or line breaks.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
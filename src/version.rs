@@ -0,0 +1,191 @@
+use crate::config::{Config, VersionBump};
+use crate::git::GitCommit;
+use crate::lint::canonical_type;
+use crate::parser::{gitmoji_name, parse_history, ParsedCommit};
+use regex::Regex;
+use serde::Serialize;
+use std::fmt;
+use std::sync::LazyLock;
+
+/// Bumps of the Gitmoji "semver" column, by gitmoji name.
+const DEFAULT_GITMOJI_BUMPS: [(&str, VersionBump); 6] = [
+    ("boom", VersionBump::Major),
+    ("sparkles", VersionBump::Minor),
+    ("bug", VersionBump::Patch),
+    ("ambulance", VersionBump::Patch),
+    ("zap", VersionBump::Patch),
+    ("lock", VersionBump::Patch),
+];
+
+static VERSION_TAG_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    // Regex: release tags like "1.2.3" or "v1.2.3". Pre-releases are skipped.
+    Regex::new(r"^(?P<prefix>v?)(?P<major>\d+)\.(?P<minor>\d+)\.(?P<patch>\d+)$")
+        .expect("Failed to compile regex")
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Version {
+    major: u64,
+    minor: u64,
+    patch: u64,
+}
+
+impl Version {
+    fn bump(self, bump: VersionBump) -> Self {
+        match bump {
+            VersionBump::Major => Version {
+                major: self.major + 1,
+                minor: 0,
+                patch: 0,
+            },
+            VersionBump::Minor => Version {
+                minor: self.minor + 1,
+                patch: 0,
+                ..self
+            },
+            VersionBump::Patch => Version {
+                patch: self.patch + 1,
+                ..self
+            },
+            VersionBump::None => self,
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// A version tag, like "v1.2.3".
+#[derive(Debug, Clone)]
+pub struct VersionTag {
+    pub name: String,
+    prefix: String,
+    version: Version,
+}
+
+impl VersionTag {
+    fn parse(name: &str) -> Option<Self> {
+        let caps = VERSION_TAG_REGEX.captures(name)?;
+        let number = |group: &str| caps[group].parse::<u64>().ok();
+        Some(VersionTag {
+            name: name.to_string(),
+            prefix: caps["prefix"].to_string(),
+            version: Version {
+                major: number("major")?,
+                minor: number("minor")?,
+                patch: number("patch")?,
+            },
+        })
+    }
+}
+
+/// Returns the tag with the highest version, ignoring tags that aren't versions.
+pub fn latest_version_tag(tags: &[String]) -> Option<VersionTag> {
+    tags.iter()
+        .filter_map(|tag| VersionTag::parse(tag))
+        .max_by_key(|tag| tag.version)
+}
+
+#[derive(Debug, Serialize)]
+pub struct NextVersion {
+    pub current_version: Option<String>,
+    pub current_tag: Option<String>,
+    pub next_version: String,
+    pub next_tag: String,
+    pub bump: VersionBump,
+    /// The commits that bump the version.
+    pub commits: Vec<BumpingCommit>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BumpingCommit {
+    pub sha: String,
+    pub header: String,
+    pub bump: VersionBump,
+}
+
+/// Computes the version that follows `latest_tag` (0.0.0 without a tag) given the
+/// commits made since.
+pub fn next_version(
+    commits: &[GitCommit],
+    config: &Config,
+    latest_tag: Option<&VersionTag>,
+) -> NextVersion {
+    let current = latest_tag.map(|tag| tag.version).unwrap_or_default();
+    let prefix = latest_tag.map_or("", |tag| tag.prefix.as_str());
+
+    let bumping_commits: Vec<BumpingCommit> = parse_history(commits, config)
+        .into_iter()
+        .map(|(sha, commit)| BumpingCommit {
+            bump: commit_bump(&commit, config),
+            header: commit.header,
+            sha,
+        })
+        .filter(|commit| commit.bump != VersionBump::None)
+        .collect();
+
+    let mut bump = bumping_commits
+        .iter()
+        .map(|commit| commit.bump)
+        .max()
+        .unwrap_or(VersionBump::None);
+    if config.version.initial_development && current.major == 0 {
+        bump = match bump {
+            VersionBump::Major => VersionBump::Minor,
+            VersionBump::Minor => VersionBump::Patch,
+            other => other,
+        };
+    }
+    let next = current.bump(bump);
+
+    NextVersion {
+        current_version: latest_tag.map(|tag| tag.version.to_string()),
+        current_tag: latest_tag.map(|tag| tag.name.clone()),
+        next_version: next.to_string(),
+        next_tag: format!("{prefix}{next}"),
+        bump,
+        commits: bumping_commits,
+    }
+}
+
+/// The highest bump of a commit: major if breaking, otherwise from its type and gitmoji.
+/// Types bump as set in `[types.<name>]`, defaulting to minor for 'feat' and patch for 'fix'.
+fn commit_bump(commit: &ParsedCommit, config: &Config) -> VersionBump {
+    if commit.is_breaking == Some(true) {
+        return VersionBump::Major;
+    }
+    let type_bump = commit.commit_type.as_deref().map(|commit_type| {
+        let commit_type = canonical_type(config, commit_type).unwrap_or(commit_type);
+        match config.types.get(commit_type).and_then(|t| t.bump) {
+            Some(bump) => bump,
+            None => match commit_type {
+                "feat" => VersionBump::Minor,
+                "fix" => VersionBump::Patch,
+                _ => VersionBump::None,
+            },
+        }
+    });
+    let gitmoji_bumps = commit.gitmoji.iter().flatten().map(|emoji| {
+        let name = gitmoji_name(emoji);
+        config
+            .version
+            .gitmoji_bumps
+            .get(&name)
+            .copied()
+            .or_else(|| {
+                DEFAULT_GITMOJI_BUMPS
+                    .iter()
+                    .find(|(default, _)| *default == name)
+                    .map(|(_, bump)| *bump)
+            })
+            .unwrap_or(VersionBump::None)
+    });
+    type_bump
+        .into_iter()
+        .chain(gitmoji_bumps)
+        .max()
+        .unwrap_or(VersionBump::None)
+}
@@ -1,14 +1,27 @@
+mod test_baseline;
+mod test_changelog;
 mod test_combined_rules;
 mod test_comments;
 mod test_commit_changes;
+mod test_commit_metadata;
 mod test_commit_range;
 mod test_config;
 mod test_conventional_commits;
+mod test_conventions;
+mod test_diagnostics;
 mod test_display;
+mod test_exit_codes;
 mod test_file_input;
 mod test_gitmoji;
+mod test_header_format;
 mod test_header_pattern_stripping;
+mod test_header_patterns;
+mod test_next_version;
 mod test_single_rule;
+mod test_spelling;
+mod test_stats;
+mod test_suppression;
+mod test_type_definitions;
 
 use super::contains;
 use super::run_isolated_git_sumi;
@@ -64,12 +77,29 @@ fn create_and_stage_file(repo_dir: &Path, file_name: &str, content: &str) {
         .success();
 }
 
+fn create_commit(repo_dir: &Path, message: &str) {
+    Command::new("git")
+        .args(["commit", "--allow-empty", "-m", message])
+        .current_dir(repo_dir)
+        .assert()
+        .success();
+}
+
+fn tag(repo_dir: &Path, name: &str) {
+    Command::new("git")
+        .args(["tag", name])
+        .current_dir(repo_dir)
+        .assert()
+        .success();
+}
+
 #[test]
 fn error_exits_no_commit() {
     let mut cmd = run_isolated_git_sumi("");
     cmd.arg("");
     let output = cmd.output().unwrap();
-    assert_eq!(output.status.code(), Some(1));
+    // No rules enabled is a configuration error.
+    assert_eq!(output.status.code(), Some(2));
 }
 
 #[test]
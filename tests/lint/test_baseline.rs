@@ -0,0 +1,255 @@
+extern crate assert_cmd;
+extern crate tempfile;
+
+use super::contains;
+use super::run_isolated_git_sumi;
+use super::setup_git_repo;
+use assert_cmd::Command;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn create_commit(repo_dir: &Path, message: &str) {
+    Command::new("git")
+        .args(["commit", "--allow-empty", "-m", message])
+        .current_dir(repo_dir)
+        .assert()
+        .success();
+}
+
+/// A repository with a clean first commit and two legacy commits that break rules.
+fn setup_legacy_repo() -> TempDir {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    fs::write(
+        repo_dir.join("sumi.toml"),
+        "conventional = true\nno_period = true\n",
+    )
+    .unwrap();
+    create_commit(repo_dir, "feat: initial commit");
+    create_commit(repo_dir, "Updated the readme.");
+    create_commit(repo_dir, "fix: handle empty input.");
+    tmp_dir
+}
+
+fn head_sha(repo_dir: &Path, rev: &str) -> String {
+    let output = Command::new("git")
+        .args(["rev-parse", rev])
+        .current_dir(repo_dir)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap().trim().to_string()
+}
+
+#[test]
+fn success_baseline_writes_violations() {
+    let tmp_dir = setup_legacy_repo();
+    let repo_dir = tmp_dir.path();
+
+    run_isolated_git_sumi("baseline")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~2"])
+        .assert()
+        .success()
+        .stdout(contains(
+            "📋 Wrote 3 violations in 2 commits to '.sumi-baseline.toml'",
+        ));
+
+    let baseline = fs::read_to_string(repo_dir.join(".sumi-baseline.toml")).unwrap();
+    assert!(baseline.starts_with("# Violations accepted when linting a commit range"));
+    assert!(baseline.contains(&format!(
+        "{} = [\"conventional\", \"no_period\"]",
+        head_sha(repo_dir, "HEAD~1")
+    )));
+    assert!(baseline.contains(&format!("{} = [\"no_period\"]", head_sha(repo_dir, "HEAD"))));
+}
+
+#[test]
+fn success_range_accepts_baseline() {
+    let tmp_dir = setup_legacy_repo();
+    let repo_dir = tmp_dir.path();
+
+    run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~2", "--to", "HEAD"])
+        .assert()
+        .code(1);
+
+    run_isolated_git_sumi("baseline")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~2"])
+        .assert()
+        .success();
+
+    run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args([
+            "--from",
+            "HEAD~2",
+            "--to",
+            "HEAD",
+            "--baseline",
+            ".sumi-baseline.toml",
+        ])
+        .assert()
+        .success()
+        .stdout(contains(
+            "📋 Accepted by the baseline: Header must not end with a period",
+        ));
+}
+
+#[test]
+fn error_range_fails_on_new_commit_violation() {
+    let tmp_dir = setup_legacy_repo();
+    let repo_dir = tmp_dir.path();
+    fs::write(
+        repo_dir.join("sumi.toml"),
+        "conventional = true\nno_period = true\nbaseline = \"sumi-baseline.toml\"\n",
+    )
+    .unwrap();
+
+    run_isolated_git_sumi("baseline")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~2"])
+        .assert()
+        .success()
+        .stdout(contains("to 'sumi-baseline.toml'"));
+    create_commit(repo_dir, "feat: add export.");
+
+    run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~3", "--to", "HEAD"])
+        .assert()
+        .code(1)
+        .stderr(contains("error[no_period]"))
+        .stderr(contains("1 out of 3 commits failed linting"));
+}
+
+#[test]
+fn error_range_fails_on_new_rule_violation_in_baselined_commit() {
+    let tmp_dir = setup_legacy_repo();
+    let repo_dir = tmp_dir.path();
+
+    run_isolated_git_sumi("baseline")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~2", "--output", "legacy.toml"])
+        .assert()
+        .success();
+
+    run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args([
+            "--from",
+            "HEAD~2",
+            "--to",
+            "HEAD",
+            "--baseline",
+            "legacy.toml",
+            "--imperative",
+        ])
+        .assert()
+        .code(1)
+        .stderr(contains("non-imperative verb: 'Updated'"))
+        .stderr(contains("1 out of 2 commits failed linting"));
+}
+
+#[test]
+fn success_range_document_lists_baselined_violations() {
+    let tmp_dir = setup_legacy_repo();
+    let repo_dir = tmp_dir.path();
+
+    run_isolated_git_sumi("baseline")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~2"])
+        .assert()
+        .success();
+
+    let output = run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args([
+            "--from",
+            "HEAD~1",
+            "--to",
+            "HEAD",
+            "--baseline",
+            ".sumi-baseline.toml",
+            "-q",
+            "-d",
+            "-f",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json[0]["status"], "passed");
+    assert_eq!(json[0]["baselined"][0], "Header must not end with a period");
+}
+
+#[test]
+fn error_missing_baseline_file() {
+    let tmp_dir = setup_legacy_repo();
+
+    run_isolated_git_sumi("")
+        .current_dir(tmp_dir.path())
+        .args([
+            "--from",
+            "HEAD~2",
+            "--to",
+            "HEAD",
+            "--baseline",
+            "missing.toml",
+        ])
+        .assert()
+        .code(2)
+        .stderr(contains("Could not load the baseline file 'missing.toml'"));
+}
+
+#[test]
+fn error_invalid_baseline_file() {
+    let tmp_dir = setup_legacy_repo();
+    let repo_dir = tmp_dir.path();
+    fs::write(repo_dir.join("baseline.toml"), "commits = 3\n").unwrap();
+
+    run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args([
+            "--from",
+            "HEAD~2",
+            "--to",
+            "HEAD",
+            "--baseline",
+            "baseline.toml",
+        ])
+        .assert()
+        .code(2)
+        .stderr(contains("Could not load the baseline file 'baseline.toml'"));
+}
+
+#[test]
+fn error_baseline_without_rules() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    create_commit(repo_dir, "feat: initial commit");
+
+    run_isolated_git_sumi("baseline")
+        .current_dir(repo_dir)
+        .assert()
+        .code(2)
+        .stderr(contains("No rules enabled"));
+}
+
+#[test]
+fn error_baseline_with_invalid_header_format() {
+    let tmp_dir = setup_legacy_repo();
+    let repo_dir = tmp_dir.path();
+    fs::write(repo_dir.join("sumi.toml"), "header_format = \"{nope}\"\n").unwrap();
+
+    run_isolated_git_sumi("baseline")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~2"])
+        .assert()
+        .code(2)
+        .stderr(contains("Header format '{nope}' is invalid"));
+    assert!(!repo_dir.join(".sumi-baseline.toml").exists());
+}
@@ -0,0 +1,236 @@
+extern crate assert_cmd;
+extern crate tempfile;
+
+use super::contains;
+use super::run_isolated_git_sumi;
+use super::{create_commit, setup_git_repo, tag};
+use assert_cmd::Command;
+use std::fs;
+use std::path::Path;
+
+fn short_sha(repo_dir: &Path, rev: &str) -> String {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short=7", rev])
+        .current_dir(repo_dir)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap().trim().to_string()
+}
+
+fn changelog(repo_dir: &Path, args: &[&str]) -> String {
+    let output = run_isolated_git_sumi("changelog")
+        .current_dir(repo_dir)
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn success_changelog_groups_commits_by_type() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+
+    create_commit(repo_dir, "feat: initial release");
+    tag(repo_dir, "v1.0.0");
+    create_commit(repo_dir, "feat(cli): add export command (#12)");
+    create_commit(repo_dir, "fix: handle empty input");
+    create_commit(repo_dir, "docs: update readme");
+
+    let expected = format!(
+        "## v1.0.0..HEAD
+
+### Features
+
+- **cli:** add export command (#12) ({})
+
+### Bug fixes
+
+- handle empty input ({})
+
+### Other changes
+
+- update readme ({})
+",
+        short_sha(repo_dir, "HEAD~2"),
+        short_sha(repo_dir, "HEAD~1"),
+        short_sha(repo_dir, "HEAD"),
+    );
+    assert_eq!(changelog(repo_dir, &["--from", "v1.0.0"]), expected);
+}
+
+#[test]
+fn success_changelog_without_from_includes_all_commits() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+
+    create_commit(repo_dir, "feat: initial release");
+    create_commit(repo_dir, "fix: handle empty input");
+
+    let output = changelog(repo_dir, &[]);
+    assert!(output.starts_with("## HEAD\n"));
+    assert!(output.contains("- initial release"));
+    assert!(output.contains("- handle empty input"));
+}
+
+#[test]
+fn success_changelog_highlights_breaking_changes() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+
+    create_commit(repo_dir, "feat: initial release");
+    create_commit(
+        repo_dir,
+        "feat(api)!: drop v1 endpoints\n\nBREAKING CHANGE: the v1 endpoints are gone",
+    );
+
+    let output = changelog(repo_dir, &["--from", "HEAD~1"]);
+    let sha = short_sha(repo_dir, "HEAD");
+    assert!(output.contains(&format!(
+        "### ⚠️ Breaking changes\n\n- **api:** the v1 endpoints are gone ({sha})"
+    )));
+    assert!(output.contains(&format!(
+        "### Features\n\n- **api:** drop v1 endpoints ({sha})"
+    )));
+}
+
+#[test]
+fn success_changelog_configured_sections_and_titles() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    fs::write(
+        repo_dir.join("sumi.toml"),
+        r#"
+[changelog]
+other_title = ""
+breaking_title = "Breaking"
+
+[[changelog.sections]]
+title = "Fixed"
+types = ["fix"]
+
+[[changelog.sections]]
+title = "Added"
+types = ["feat"]
+"#,
+    )
+    .unwrap();
+
+    create_commit(repo_dir, "chore: initial commit");
+    create_commit(repo_dir, "feat: add export");
+    create_commit(repo_dir, "fix: handle empty input");
+    create_commit(repo_dir, "ci: cache dependencies");
+
+    let output = changelog(repo_dir, &["--from", "HEAD~3"]);
+    let fixed = output.find("### Fixed").expect("Missing 'Fixed' section");
+    let added = output.find("### Added").expect("Missing 'Added' section");
+    assert!(fixed < added, "Sections must follow the configured order");
+    assert!(!output.contains("cache dependencies"));
+    assert!(!output.contains("Breaking"));
+}
+
+#[test]
+fn success_changelog_links_references() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    fs::write(
+        repo_dir.join("sumi.toml"),
+        r#"
+[changelog]
+reference_url = "https://example.com/issues/{reference}"
+"#,
+    )
+    .unwrap();
+
+    create_commit(repo_dir, "chore: initial commit");
+    create_commit(repo_dir, "fix: handle empty input\n\nCloses #34");
+
+    let output = changelog(repo_dir, &["--from", "HEAD~1"]);
+    assert!(output.contains("- handle empty input ([#34](https://example.com/issues/34))"));
+}
+
+#[test]
+fn success_changelog_entry_format_and_template() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    fs::write(
+        repo_dir.join("sumi.toml"),
+        r#"
+[changelog]
+entry_format = "* {type}({scope}): {description} {references}"
+"#,
+    )
+    .unwrap();
+    let template_path = repo_dir.join("template.md");
+    fs::write(
+        &template_path,
+        "# Release notes for {range}\n\n{sections}\n",
+    )
+    .unwrap();
+
+    create_commit(repo_dir, "chore: initial commit");
+    create_commit(repo_dir, "feat(cli): add export");
+
+    let output = changelog(
+        repo_dir,
+        &[
+            "--from",
+            "HEAD~1",
+            "--template",
+            template_path.to_str().unwrap(),
+        ],
+    );
+    assert_eq!(
+        output,
+        "# Release notes for HEAD~1..HEAD\n\n### Features\n\n* feat(cli): add export\n"
+    );
+}
+
+#[test]
+fn success_changelog_empty_range() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+
+    create_commit(repo_dir, "feat: initial release");
+
+    run_isolated_git_sumi("changelog")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD", "--to", "HEAD"])
+        .assert()
+        .success()
+        .stdout(contains("No commits found in range HEAD..HEAD"));
+}
+
+#[test]
+fn error_changelog_invalid_range() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+
+    create_commit(repo_dir, "feat: initial release");
+
+    run_isolated_git_sumi("changelog")
+        .current_dir(repo_dir)
+        .args(["--from", "nonexistent"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn error_changelog_missing_template() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+
+    create_commit(repo_dir, "feat: initial release");
+
+    run_isolated_git_sumi("changelog")
+        .current_dir(repo_dir)
+        .args(["--template", "missing.md"])
+        .assert()
+        .failure()
+        .stderr(contains("Could not read template from 'missing.md'"));
+}
@@ -112,7 +112,7 @@ fn error_conventional_fails_parsing_proceeds() {
         .arg("test(): add unit tests\nMore details.")
         .assert()
         .failure()
-        .stderr(contains("Missing type in the commit summary"))
+        .stderr(contains("Commit scope must not be empty"))
         .stderr(contains("Found 3 linting errors"))
         .stderr(contains("Separate header from body with a blank line"))
         .stderr(contains("Header must contain exactly 1 emoji, found 0"));
@@ -0,0 +1,246 @@
+extern crate assert_cmd;
+extern crate tempfile;
+
+use super::contains;
+use super::run_isolated_git_sumi;
+use super::setup_git_repo;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+
+fn git(repo_dir: &Path, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(repo_dir)
+        .assert()
+        .success();
+}
+
+fn create_commit(repo_dir: &Path, author: &str, message: &str) {
+    git(
+        repo_dir,
+        &[
+            "-c",
+            &format!("user.name={author}"),
+            "-c",
+            &format!("user.email={}@example.com", author.to_lowercase()),
+            "commit",
+            "--allow-empty",
+            "-m",
+            message,
+        ],
+    );
+}
+
+#[test]
+fn success_range_display_includes_metadata() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+
+    create_commit(repo_dir, "Alice", "feat: initial commit");
+    create_commit(repo_dir, "Bob", "fix: handle empty input");
+
+    let output = run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args([
+            "--from", "HEAD~1", "--to", "HEAD", "-C", "-q", "-d", "-f", "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let metadata = &json[0]["metadata"];
+    assert_eq!(metadata["author_name"], "Bob");
+    assert_eq!(metadata["author_email"], "bob@example.com");
+    assert_eq!(metadata["committer_name"], "Bob");
+    assert_eq!(metadata["parents"], 1);
+    assert!(metadata["author_date"].as_str().unwrap().contains('T'));
+    assert!(metadata["committer_date"].as_str().unwrap().contains('T'));
+}
+
+#[test]
+fn success_range_display_toml_and_table_include_metadata() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+
+    create_commit(repo_dir, "Alice", "feat: initial commit");
+    create_commit(repo_dir, "Bob", "fix: handle empty input");
+
+    run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args([
+            "--from", "HEAD~1", "--to", "HEAD", "-C", "-q", "-d", "-f", "toml",
+        ])
+        .assert()
+        .success()
+        .stdout(contains("[commits.metadata]\nauthor_name = \"Bob\""));
+
+    run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~1", "--to", "HEAD", "-C", "-q", "-d"])
+        .assert()
+        .success()
+        .stdout(contains("Bob <bob@example.com>"))
+        .stdout(contains("Parents"));
+}
+
+#[test]
+fn success_single_message_has_no_metadata() {
+    run_isolated_git_sumi("")
+        .args(["-C", "-q", "-d", "-f", "json", "feat: add export"])
+        .assert()
+        .success()
+        .stdout(contains("\"description\": \"add export\""))
+        .stdout(contains("metadata").not());
+}
+
+#[test]
+fn success_skip_authors() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+
+    create_commit(repo_dir, "Alice", "feat: initial commit");
+    create_commit(repo_dir, "dependabot[bot]", "Bump serde from 1.0 to 2.0");
+    create_commit(repo_dir, "Alice", "fix: handle empty input");
+
+    run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~2", "--to", "HEAD", "-C"])
+        .assert()
+        .failure()
+        .stderr(contains("1 out of 2 commits failed linting"));
+
+    run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~2", "--to", "HEAD", "-C"])
+        .args(["--skip-authors", "*[bot]"])
+        .assert()
+        .success()
+        .stdout(contains("Skipped commit by dependabot[bot]"));
+}
+
+#[test]
+fn success_skip_authors_matches_email() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    fs::write(
+        repo_dir.join("sumi.toml"),
+        "conventional = true\nskip_authors = [\"renovate@example.com\"]\n",
+    )
+    .unwrap();
+
+    create_commit(repo_dir, "Alice", "feat: initial commit");
+    create_commit(repo_dir, "Renovate", "Update dependencies");
+
+    run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~1", "--to", "HEAD"])
+        .assert()
+        .success()
+        .stdout(contains("Skipped commit by Renovate"));
+}
+
+#[test]
+fn success_skip_merge_commits() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+
+    create_commit(repo_dir, "Alice", "feat: initial commit");
+    git(repo_dir, &["checkout", "-q", "-b", "side"]);
+    create_commit(repo_dir, "Alice", "fix: handle empty input");
+    git(repo_dir, &["checkout", "-q", "-"]);
+    git(
+        repo_dir,
+        &[
+            "merge",
+            "-q",
+            "--no-ff",
+            "side",
+            "-m",
+            "Merge branch 'side'",
+        ],
+    );
+
+    run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~1", "--to", "HEAD", "-C"])
+        .assert()
+        .failure()
+        .stderr(contains("1 out of 2 commits failed linting"));
+
+    run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~1", "--to", "HEAD", "-C"])
+        .arg("--skip-merge-commits")
+        .assert()
+        .success()
+        .stdout(contains("Skipped merge commit by Test User"));
+}
+
+#[test]
+fn success_author_overrides() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    fs::write(
+        repo_dir.join("sumi.toml"),
+        r#"
+conventional = true
+max_header_length = 30
+
+[[author_overrides]]
+authors = ["*[bot]"]
+max_header_length = 60
+"#,
+    )
+    .unwrap();
+
+    create_commit(repo_dir, "Alice", "feat: initial commit");
+    create_commit(
+        repo_dir,
+        "renovate[bot]",
+        "chore(deps): update rust crate serde to 2.0.1",
+    );
+
+    run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~1", "--to", "HEAD"])
+        .assert()
+        .success();
+
+    create_commit(
+        repo_dir,
+        "Alice",
+        "chore(deps): update rust crate serde to 2.0.1",
+    );
+    run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~2", "--to", "HEAD"])
+        .assert()
+        .failure()
+        .stderr(contains("1 out of 2 commits failed linting"));
+}
+
+#[test]
+fn error_author_overrides_invalid_value() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    fs::write(
+        repo_dir.join("sumi.toml"),
+        r#"
+conventional = true
+
+[[author_overrides]]
+authors = ["*[bot]"]
+max_header_length = "long"
+"#,
+    )
+    .unwrap();
+
+    run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .arg("feat: add export")
+        .assert()
+        .failure()
+        .stderr(contains("max_header_length"));
+}
@@ -797,3 +797,137 @@ fn success_display_in_range_mode() {
         "Expected display output for the passing commit, got stdout: {stdout}"
     );
 }
+
+fn create_mixed_range(repo_dir: &Path) {
+    create_commit(repo_dir, "init.txt", "feat: init");
+    create_commit(repo_dir, "a.txt", "feat: add a");
+    create_commit(repo_dir, "b.txt", "not conventional");
+    create_commit(repo_dir, "c.txt", "fix(api): handle c");
+}
+
+#[test]
+fn error_range_display_json_is_a_single_array() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    create_mixed_range(repo_dir);
+
+    let output = run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~3", "--to", "HEAD", "-C", "-d", "-f", "json"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let entries = json.as_array().unwrap();
+    assert_eq!(entries.len(), 3);
+    let statuses: Vec<&str> = entries
+        .iter()
+        .map(|entry| entry["status"].as_str().unwrap())
+        .collect();
+    assert_eq!(statuses, ["passed", "failed", "passed"]);
+    assert_eq!(entries[0]["description"], "add a");
+    assert_eq!(entries[0]["sha"].as_str().unwrap().len(), 40);
+    assert!(entries[1]["errors"][0]
+        .as_str()
+        .unwrap()
+        .contains("Failed to parse as a conventional commit"));
+    assert_eq!(entries[2]["scope"], "api");
+    assert_eq!(entries[2]["metadata"]["author_name"], "Test User");
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("1 out of 3 commits failed linting"));
+    assert!(stderr.contains("See the `errors` field of each commit"));
+    assert!(!stderr.contains("See the errors above"));
+}
+
+#[test]
+fn success_range_display_ndjson_prints_one_object_per_line() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    create_commit(repo_dir, "init.txt", "feat: init");
+    create_commit(repo_dir, "a.txt", "feat: add a");
+    create_commit(repo_dir, "b.txt", "fix: handle b");
+
+    let output = run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args([
+            "--from", "HEAD~2", "--to", "HEAD", "-C", "-d", "-f", "ndjson",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let entries: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["commit_type"], "feat");
+    assert_eq!(entries[1]["commit_type"], "fix");
+    assert!(entries
+        .iter()
+        .all(|entry| entry["status"] == "passed" && entry["errors"] == serde_json::json!([])));
+}
+
+#[test]
+fn error_range_display_toml_is_an_array_of_tables() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    create_mixed_range(repo_dir);
+
+    let output = run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~3", "--to", "HEAD", "-C", "-d", "-f", "toml"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let document: toml::Table = stdout.parse().unwrap();
+    let commits = document["commits"].as_array().unwrap();
+    assert_eq!(commits.len(), 3);
+    assert_eq!(commits[1]["status"].as_str(), Some("failed"));
+    assert_eq!(
+        commits[2]["metadata"]["committer_email"].as_str(),
+        Some("test@example.com")
+    );
+}
+
+#[test]
+fn success_range_display_document_lists_skipped_commits() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    create_commit(repo_dir, "init.txt", "feat: init");
+    create_commit(repo_dir, "a.txt", "not conventional");
+    create_commit(repo_dir, "b.txt", "fix: handle b");
+
+    let output = run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~2", "--to", "HEAD", "-C", "-d", "-f", "json"])
+        .env("GIT_SUMI_SKIP_AUTHORS", "Test User")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let entries = json.as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert!(entries.iter().all(|entry| entry["status"] == "skipped"));
+    assert_eq!(entries[0]["metadata"]["author_name"], "Test User");
+}
+
+#[test]
+fn success_range_display_document_of_empty_range() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    create_commit(repo_dir, "init.txt", "feat: init");
+
+    run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD", "--to", "HEAD", "-C", "-d", "-f", "json"])
+        .assert()
+        .success()
+        .stdout("[]\n");
+}
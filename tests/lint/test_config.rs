@@ -347,12 +347,35 @@ quiet = false
 display = false
 
 # Sets display format.
-# Options: 'cli', 'json', 'table', 'toml'.
+# Options: 'cli', 'json', 'ndjson', 'table', 'toml'.
 format = "cli"
 
+# Colors error diagnostics.
+# Options: 'auto', 'always', 'never'. 'auto' colors output to a terminal, unless NO_COLOR is set.
+color = "auto"
+
 # Processes each non-empty line as an individual commit.
 split_lines = false
 
+# Fixes the commit header when possible.
+# Replaces type and scope aliases and typos with the allowed values.
+fix = false
+
+# List of authors whose commits are not linted in a revision range.
+# Matches the author name or email. Use '*' as a wildcard. Example: ["*[bot]"].
+skip_authors = []
+
+# Do not lint merge commits in a revision range.
+skip_merge_commits = false
+
+# List of rules that can't be disabled with a Sumi-Disable trailer.
+# Use '*' as a wildcard. Example: ["conventional", "breaking_*"].
+unsuppressible_rules = []
+
+# Path to a baseline file of violations accepted in a revision range.
+# Create it with `git-sumi baseline`. Any other violation still fails.
+baseline = ""
+
 # Rule: Include one valid Gitmoji.
 # See https://gitmoji.dev/.
 gitmoji = false
@@ -365,17 +388,71 @@ description_case = "any"
 # Example: 'Fix bug' instead of 'Fixed bug'.
 imperative = false
 
+# Language of the commit messages for the imperative rule.
+# Options: 'de', 'en', 'es'.
+language = "en"
+
 # Rule: Do not end commit header with a period.
 no_period = false
 
+# Rule: Check the spelling of the description and body.
+# Add project-specific words to '.sumi-words.txt', one per line.
+spelling = false
+
+# Rule: Reject placeholder words, profanity and non-inclusive terms.
+# See the built-in list at https://sumi.rs/docs/rules#forbidden-words.
+forbidden_words = false
+
+# Rule: List of additional forbidden words.
+# Use 'word=replacement' to suggest an alternative. Example: ["temp", "master=main"].
+forbidden_words_extra = []
+
 # Rule: Header length limit.
 # A value of 0 disables the rule.
 max_header_length = 0
 
-# Rule: Body line length limit.
+# Rule: Minimum description length.
+# A value of 0 disables the rule.
+min_description_length = 0
+
+# Rule: Minimum number of words in the description.
 # A value of 0 disables the rule.
+min_description_words = 0
+
+# Rule: Reject vague descriptions like 'update' or 'misc'.
+no_vague_description = false
+
+# Rule: Body line length limit.
+# Measured in terminal columns. A value of 0 disables the rule.
 max_body_length = 0
 
+# Rule: Body lines exempt from the length limit.
+# Options: 'url', 'code', 'quote', 'trailer'. Example: ["url", "code"].
+body_length_exemptions = []
+
+# Rule: List of commit types that require a body.
+# An empty list disables the rule. Example: ["feat", "fix"].
+body_required_types = []
+
+# Rule: Require a body when the header is longer than this.
+# A value of 0 disables the rule.
+body_required_header_length = 0
+
+# Rule: List of header pattern captures that require a body.
+# Names of capture groups in 'header_pattern'. Example: ["breaking"].
+body_required_captures = []
+
+# Rule: Minimum body length.
+# A value of 0 disables the rule.
+min_body_length = 0
+
+# Rule: Body must not just repeat the header.
+no_repeated_header = false
+
+# Rule: Use the same marker for all bullets in a list.
+# Example: don't mix '-' and '*'.
+consistent_bullets = false
+
 # Rule: No leading, trailing, or consecutive spaces.
 whitespace = false
 
@@ -383,6 +460,10 @@ whitespace = false
 # See https://www.conventionalcommits.org/.
 conventional = false
 
+# Rule: Commit message convention to follow.
+# Options: 'none', 'conventional', 'angular', 'kernel', 'gitmoji', 'jira', 'chromium'.
+convention = "none"
+
 # Rule: List of allowed commit scopes.
 # An empty list allows all scopes. Example: ["docs", "cli"].
 scopes_allowed = []
@@ -391,12 +472,71 @@ scopes_allowed = []
 # An empty list allows all types. Example: ["feat", "fix", "docs"].
 types_allowed = []
 
+# Rule: Commit type must be lowercase.
+lowercase_type = false
+
+# Rule: Require a commit scope.
+scope_required = false
+
+# Rule: List of commit types that require a scope.
+# Example: ["feat", "fix"].
+scope_required_types = []
+
+# Rule: Case style of the commit scope.
+# Options: 'any', 'lower', 'kebab'.
+scope_case = "any"
+
+# Rule: Allow only one commit scope.
+# Multiple scopes are separated by commas, like 'feat(api,cli)'.
+no_multiple_scopes = false
+
+# Characters that separate multiple scopes.
+# Each character is a delimiter. An empty value uses ','. Example: ",|".
+scope_delimiters = ""
+
+# Separator between the components of a hierarchical scope.
+# Components are validated individually. An empty value disables it. Example: "/".
+scope_hierarchy_separator = ""
+
+# Rule: Require exactly one space after the colon in the header.
+single_space_after_colon = false
+
+# Rule: Require a 'BREAKING CHANGE:' footer when the header uses '!'.
+breaking_requires_footer = false
+
+# Rule: Require '!' in the header when there's a 'BREAKING CHANGE:' footer.
+breaking_requires_bang = false
+
+# Rule: Minimum length of the breaking change description.
+# A value of 0 disables the rule.
+min_breaking_description_length = 0
+
+# Rule: List of commit types that can't be breaking changes.
+# Example: ["docs", "chore"].
+breaking_forbidden_types = []
+
+# Rule: List of scopes that can't be breaking changes.
+# Example: ["deps"].
+breaking_forbidden_scopes = []
+
+# Rule: List of branches where breaking changes aren't allowed.
+# Supports '*' wildcards. Example: ["release/*", "v1.x"].
+breaking_forbidden_branches = []
+
 # Rule: Header must match regex pattern.
 # Example: '^JIRA-\d+:'.
 header_pattern = ""
 
 # Rule: Remove header pattern before running other validation rules.
-strip_header_pattern = false"#
+strip_header_pattern = false
+
+# Rule: Header must follow a template.
+# Placeholders: {gitmoji}, {type}, {scope}, {breaking}, {description}, {ticket}. Example: "{type}({scope}): {description}".
+header_format = ""
+
+# Whether headers must match all or any of the header patterns.
+# Options: 'all', 'any'. Applies to 'header_pattern' and [[header_patterns]].
+header_patterns_match = "all""#
 }
 
 #[test]
@@ -773,3 +913,24 @@ fn error_no_rules_enabled() {
         .failure()
         .stderr(contains("No rules enabled"));
 }
+
+#[test]
+fn error_forbidden_words_too_large() {
+    let tmp_dir = tempdir().unwrap();
+    let config_path = tmp_dir.path().join("sumi.toml");
+    let word = "ab".repeat(100_000);
+    fs::write(
+        &config_path,
+        format!("forbidden_words_extra = [\"{word}\"]"),
+    )
+    .unwrap();
+
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--config")
+        .arg(&config_path)
+        .arg("Merge branch main")
+        .assert()
+        .code(2)
+        .stderr(contains("Forbidden words are invalid"))
+        .stderr(contains("Forbidden word '").not());
+}
@@ -2,6 +2,9 @@ extern crate tempfile;
 
 use super::contains;
 use super::run_isolated_git_sumi;
+use super::setup_git_repo;
+use assert_cmd::Command;
+use predicates::prelude::*;
 
 #[test]
 fn success_with_empty_types_and_scopes() {
@@ -114,7 +117,12 @@ fn error_conventional_commit_empty_scope() {
         .assert()
         .failure()
         .stderr(contains(
-            "Missing type in the commit summary, expected `type: description`",
+            "error[conventional]: Commit scope must not be empty
+ --> line 1, column 7
+  |
+1 | bugfix(): fix some bug
+  |       ^^
+  = help: add a scope inside the parentheses, or remove them",
         ));
 }
 
@@ -126,3 +134,426 @@ fn success_conventional_commit() {
         .assert()
         .success();
 }
+
+#[test]
+fn error_breaking_bang_without_footer() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--breaking-requires-footer")
+        .arg("feat!: drop support for Node 16")
+        .assert()
+        .failure()
+        .stderr(contains("must include a 'BREAKING CHANGE:' footer"));
+}
+
+#[test]
+fn success_breaking_bang_with_footer() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--breaking-requires-footer")
+        .arg("--breaking-requires-bang")
+        .arg("feat(api)!: drop v1 endpoints\n\nBREAKING CHANGE: the v1 endpoints are gone")
+        .assert()
+        .success();
+}
+
+#[test]
+fn error_breaking_footer_without_bang() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--breaking-requires-bang")
+        .arg("--gitmoji")
+        .arg("✨ feat: drop v1 endpoints\n\nBREAKING CHANGE: the v1 endpoints are gone")
+        .assert()
+        .failure()
+        .stderr(contains("must add '!' before the colon"));
+}
+
+#[test]
+fn error_breaking_description_too_short() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--min-breaking-description-length")
+        .arg("20")
+        .arg("feat!: drop v1\n\nBREAKING-CHANGE: gone")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Breaking change description is too short (4 < 20)",
+        ));
+}
+
+#[test]
+fn error_breaking_change_forbidden_type_and_scope() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--breaking-forbidden-types")
+        .arg("docs,chore")
+        .arg("--breaking-forbidden-scopes")
+        .arg("deps")
+        .arg("chore(deps)!: bump serde to 2.0")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Breaking changes are not allowed for type 'chore'",
+        ))
+        .stderr(contains(
+            "Breaking changes are not allowed for scope 'deps'",
+        ));
+}
+
+#[test]
+fn success_non_breaking_change_with_breaking_rules() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--breaking-forbidden-types")
+        .arg("chore")
+        .arg("--breaking-requires-footer")
+        .arg("chore(deps): bump serde to 1.0.200")
+        .assert()
+        .success();
+}
+
+#[test]
+fn error_breaking_change_on_forbidden_branch() {
+    let tmp_dir = setup_git_repo();
+    Command::new("git")
+        .args(["checkout", "-b", "release/1.x"])
+        .current_dir(tmp_dir.path())
+        .assert()
+        .success();
+
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("--breaking-forbidden-branches")
+        .arg("main,release/*")
+        .arg("feat!: drop v1 endpoints")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Breaking changes are not allowed on branch 'release/1.x' (matches 'release/*')",
+        ));
+
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("--breaking-forbidden-branches")
+        .arg("main")
+        .arg("feat!: drop v1 endpoints")
+        .assert()
+        .success();
+}
+
+#[test]
+fn error_uppercase_type() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--lowercase-type")
+        .arg("Feat: add login form")
+        .assert()
+        .failure()
+        .stderr(contains("Commit type 'Feat' must be lowercase"));
+}
+
+#[test]
+fn error_missing_scope() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--scope-required")
+        .arg("feat: add login form")
+        .assert()
+        .failure()
+        .stderr(contains("Commit scope is required"));
+}
+
+#[test]
+fn scope_required_for_listed_types_only() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--scope-required-types")
+        .arg("feat,fix")
+        .arg("fix: handle empty input")
+        .assert()
+        .failure()
+        .stderr(contains("Commit scope is required for type 'fix'"));
+
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--scope-required-types")
+        .arg("feat,fix")
+        .arg("docs: fix typo in README")
+        .assert()
+        .success();
+}
+
+#[test]
+fn error_scope_not_kebab_case() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--scope-case")
+        .arg("kebab")
+        .arg("feat(user-auth,userProfile): add avatars")
+        .assert()
+        .failure()
+        .stderr(contains("Commit scope 'userProfile' must be kebab-case"));
+}
+
+#[test]
+fn success_scope_lowercase() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--scope-case")
+        .arg("lower")
+        .arg("feat(user_auth): add avatars")
+        .assert()
+        .success();
+}
+
+#[test]
+fn error_multiple_scopes() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--no-multiple-scopes")
+        .arg("feat(api,cli): add export command")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Only one commit scope is allowed, found 'api,cli'",
+        ));
+}
+
+#[test]
+fn multiple_scopes_are_validated_individually() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--scopes-allowed")
+        .arg("api,cli")
+        .arg("feat(api, cli): add export command")
+        .assert()
+        .success();
+
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--scopes-allowed")
+        .arg("api,cli")
+        .arg("feat(api,web): add export command")
+        .assert()
+        .failure()
+        .stderr(contains("Invalid commit scope 'web'"));
+}
+
+#[test]
+fn error_spaces_after_colon() {
+    for (header, spaces) in [("feat:add login", 0), ("feat(ui)!:  add login", 2)] {
+        let mut cmd = run_isolated_git_sumi("");
+        cmd.arg("--single-space-after-colon")
+            .arg(header)
+            .assert()
+            .failure()
+            .stderr(contains(format!(
+                "Expected exactly one space after the colon in the header, found {spaces}"
+            )));
+    }
+}
+
+#[test]
+fn success_custom_scope_delimiters() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--scopes-allowed")
+        .arg("api,cli")
+        .arg("--scope-delimiters")
+        .arg("|+")
+        .arg("feat(api|cli + api): add export command")
+        .assert()
+        .success();
+}
+
+#[test]
+fn hierarchical_scope_components_are_validated_individually() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--scopes-allowed")
+        .arg("api,auth")
+        .arg("--scope-hierarchy-separator")
+        .arg("/")
+        .arg("feat(api/auth): add token refresh")
+        .assert()
+        .success();
+
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--scopes-allowed")
+        .arg("api,auth")
+        .arg("--scope-hierarchy-separator")
+        .arg("/")
+        .arg("feat(api/billing,auth): add token refresh")
+        .assert()
+        .failure()
+        .stderr(contains("Invalid commit scope 'billing'"));
+}
+
+#[test]
+fn success_hierarchical_scope_allowed_as_a_whole() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--scopes-allowed")
+        .arg("api/auth")
+        .arg("--scope-hierarchy-separator")
+        .arg("/")
+        .arg("feat(api/auth): add token refresh")
+        .assert()
+        .success();
+}
+
+#[test]
+fn error_hierarchical_scope_component_case() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--scope-case")
+        .arg("kebab")
+        .arg("--scope-hierarchy-separator")
+        .arg("/")
+        .arg("feat(api/userAuth): add token refresh")
+        .assert()
+        .failure()
+        .stderr(contains("Commit scope 'userAuth' must be kebab-case"));
+}
+
+#[test]
+fn error_invalid_type_and_scope_suggest_closest_values() {
+    let test_cases = [
+        (
+            "feta: add login",
+            "Invalid commit type 'feta'",
+            "Did you mean 'feat'?",
+        ),
+        (
+            "FEAT: add login",
+            "Invalid commit type 'FEAT'",
+            "Did you mean 'feat'?",
+        ),
+        (
+            "refac: tidy up",
+            "Invalid commit type 'refac'",
+            "Did you mean 'refactor'?",
+        ),
+        (
+            "fix(clie): handle input",
+            "Invalid commit scope 'clie'",
+            "Did you mean 'cli'?",
+        ),
+        (
+            "f: add login",
+            "Invalid commit type 'f'",
+            "Did you mean 'feat', 'fix'?",
+        ),
+    ];
+    for (header, error, suggestion) in test_cases {
+        let mut cmd = run_isolated_git_sumi("");
+        cmd.arg("--types-allowed")
+            .arg("feat,fix,refactor")
+            .arg("--scopes-allowed")
+            .arg("cli,api")
+            .arg(header)
+            .assert()
+            .failure()
+            .stderr(contains(error))
+            .stderr(contains(suggestion));
+    }
+}
+
+#[test]
+fn error_invalid_type_without_close_match_has_no_suggestion() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--types-allowed")
+        .arg("feat,fix")
+        .arg("docs: update README")
+        .assert()
+        .failure()
+        .stderr(contains("Allowed types are: [feat, fix]\n"));
+}
+
+#[test]
+fn success_fix_applies_unambiguous_suggestions() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--fix")
+        .arg("--types-allowed")
+        .arg("feat,fix")
+        .arg("--scopes-allowed")
+        .arg("cli,api")
+        .arg("--scope-hierarchy-separator")
+        .arg("/")
+        .arg("feta(Api/clie, cli): add export command")
+        .assert()
+        .success()
+        .stdout(contains(
+            "Fixed header: \"feat(api/cli, cli): add export command\"",
+        ));
+}
+
+#[test]
+fn error_fix_skips_ambiguous_suggestions() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--fix")
+        .arg("--types-allowed")
+        .arg("feat,fix")
+        .arg("f: add login")
+        .assert()
+        .failure()
+        .stdout(contains("Fixed header").not())
+        .stderr(contains("Did you mean 'feat', 'fix'?"));
+}
+
+#[test]
+fn error_parse_failure_reports_all_violations() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.args(["-C", "-I", "-T", "feat,fix", "--scopes-allowed", "cli"])
+        .arg("chore (api) :Added export")
+        .assert()
+        .failure()
+        .stderr(contains("Failed to parse as a conventional commit"))
+        .stderr(contains("Invalid commit type 'chore'"))
+        .stderr(contains("Invalid commit scope 'api'"))
+        .stderr(contains("non-imperative verb: 'Added'"))
+        .stderr(contains("Found 4 linting errors"));
+}
+
+#[test]
+fn error_parse_failure_position() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.args(["-C", "feat(cli: add export"])
+        .assert()
+        .failure()
+        .stderr(contains(
+            "error[conventional]: Failed to parse as a conventional commit: 'Missing type in the commit summary, expected `type: description`'
+ --> line 1, column 5
+  |
+1 | feat(cli: add export
+  |     ^",
+        ));
+}
+
+#[test]
+fn error_parse_failure_position_after_gitmoji() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.args(["-C", "-G", "✨ feat(cli: add export"])
+        .assert()
+        .failure()
+        .stderr(contains(" --> line 1, column 7"));
+}
+
+#[test]
+fn error_parse_failure_position_of_missing_body_separator() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.args(["-C", "feat: add export\nto CSV"])
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Failed to parse as a conventional commit: 'Separate header from body with a blank line'
+ --> line 2, column 1",
+        ));
+}
+
+#[test]
+fn success_valid_rules_pass_on_best_effort_parse() {
+    // Only the parse failure is reported: the type, scope and description are valid.
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.args(["-C", "-I", "-T", "feat", "--scopes-allowed", "cli"])
+        .arg("feat (cli): add export")
+        .assert()
+        .failure()
+        .stderr(contains("Failed to parse as a conventional commit"))
+        .stderr(contains("Found 1 linting error"))
+        .stderr(contains("Invalid commit").not());
+}
+
+#[test]
+fn error_empty_message_reports_all_violations() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.args(["-G", "-E", "upper", ""])
+        .assert()
+        .failure()
+        .stderr(contains("Header must not be empty"))
+        .stderr(contains("Header must contain exactly 1 emoji, found 0"))
+        .stderr(contains("Found 2 linting errors"));
+}
@@ -0,0 +1,246 @@
+//! Tests for the `convention` option: Angular, Linux kernel, gitmoji, Jira and Chromium headers.
+
+use super::contains;
+use super::run_isolated_git_sumi;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn success_angular_commit() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--convention")
+        .arg("angular")
+        .arg("feat(router): add lazy loading")
+        .assert()
+        .success();
+}
+
+#[test]
+fn error_angular_commit_invalid_type() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--convention")
+        .arg("angular")
+        .arg("chore: bump version")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Failed to parse with the Angular convention: 'Invalid commit type 'chore'. Allowed types are: [build, ci, docs, feat, fix, perf, refactor, test]'",
+        ));
+}
+
+#[test]
+fn success_kernel_commit_display() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("-d")
+        .arg("--format")
+        .arg("json")
+        .arg("--convention")
+        .arg("kernel")
+        .arg("net: ipv4: fix leak in route cache\n\nThe cache entry was never freed.\n\nSigned-off-by: Test User <test@example.com>")
+        .assert()
+        .success()
+        .stdout(contains("\"scope\": \"net: ipv4\""))
+        .stdout(contains("\"net\",\n    \"ipv4\""))
+        .stdout(contains("\"description\": \"fix leak in route cache\""))
+        .stdout(contains("\"body\": \"The cache entry was never freed.\""))
+        .stdout(contains(
+            "\"Signed-off-by: Test User <test@example.com>\"",
+        ));
+}
+
+#[test]
+fn error_kernel_commit_missing_subsystem() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--convention")
+        .arg("kernel")
+        .arg("Fix leak in route cache")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Failed to parse with the Linux kernel convention: 'Header must follow the format 'subsystem: description''",
+        ));
+}
+
+#[test]
+fn success_kernel_commit_scopes_allowed() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--convention")
+        .arg("kernel")
+        .arg("--scopes-allowed")
+        .arg("net,ipv4,mm")
+        .arg("net: ipv4: fix leak in route cache")
+        .assert()
+        .success();
+}
+
+#[test]
+fn error_kernel_commit_scope_not_allowed() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--convention")
+        .arg("kernel")
+        .arg("--scopes-allowed")
+        .arg("net,mm")
+        .arg("net: ipv6: fix leak in route cache")
+        .assert()
+        .failure()
+        .stderr(contains("Invalid commit scope 'ipv6'"));
+}
+
+#[test]
+fn success_gitmoji_commit_type_from_unicode() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("-d")
+        .arg("--format")
+        .arg("json")
+        .arg("--convention")
+        .arg("gitmoji")
+        .arg("✨ Add export command")
+        .assert()
+        .success()
+        .stdout(contains("\"commit_type\": \"sparkles\""))
+        .stdout(contains("\"description\": \"Add export command\""));
+}
+
+#[test]
+fn success_gitmoji_commit_type_from_shortcode() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--convention")
+        .arg("gitmoji")
+        .arg("--types-allowed")
+        .arg("bug,sparkles")
+        .arg(":bug: Handle empty input")
+        .assert()
+        .success();
+}
+
+#[test]
+fn error_gitmoji_commit_type_not_allowed() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--convention")
+        .arg("gitmoji")
+        .arg("--types-allowed")
+        .arg("bug,sparkles")
+        .arg("🔥 Remove dead code")
+        .assert()
+        .failure()
+        .stderr(contains("Invalid commit type 'fire'"));
+}
+
+#[test]
+fn error_gitmoji_commit_without_leading_gitmoji() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--convention")
+        .arg("gitmoji")
+        .arg("Add export command ✨")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Failed to parse with the gitmoji convention: 'Header must follow the format '<gitmoji> description''",
+        ));
+}
+
+#[test]
+fn success_jira_commit_display() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("-d")
+        .arg("--format")
+        .arg("json")
+        .arg("--convention")
+        .arg("jira")
+        .arg("PROJ-123: add login page")
+        .assert()
+        .success()
+        .stdout(contains("\"commit_type\": \"PROJ\""))
+        .stdout(contains("\"scope\": \"PROJ-123\""))
+        .stdout(contains("\"description\": \"add login page\""))
+        .stdout(contains("\"references\": [\n    \"PROJ-123\"\n  ]"));
+}
+
+#[test]
+fn error_jira_commit_project_not_allowed() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--convention")
+        .arg("jira")
+        .arg("--types-allowed")
+        .arg("PROJ")
+        .arg("OPS-7: rotate credentials")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Invalid commit type 'OPS'. Allowed types are: [PROJ]",
+        ));
+}
+
+#[test]
+fn error_jira_commit_missing_issue_key() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--convention")
+        .arg("jira")
+        .arg("proj-123: add login page")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Failed to parse with the Jira convention: 'Header must follow the format 'PROJ-123: description''",
+        ));
+}
+
+#[test]
+fn error_jira_commit_empty_description() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--convention")
+        .arg("jira")
+        .arg("PROJ-123:")
+        .assert()
+        .failure()
+        .stderr(contains("Description must not be empty"));
+}
+
+#[test]
+fn success_chromium_commit_display() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("-d")
+        .arg("--format")
+        .arg("json")
+        .arg("--convention")
+        .arg("chromium")
+        .arg("[views][ash] Fix crash on shelf resize")
+        .assert()
+        .success()
+        .stdout(contains("\"scope\": \"views, ash\""))
+        .stdout(contains("\"views\",\n    \"ash\""))
+        .stdout(contains("\"description\": \"Fix crash on shelf resize\""));
+}
+
+#[test]
+fn error_chromium_commit_multiple_components() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--convention")
+        .arg("chromium")
+        .arg("--no-multiple-scopes")
+        .arg("[views][ash] Fix crash on shelf resize")
+        .assert()
+        .failure()
+        .stderr(contains("views, ash"));
+}
+
+#[test]
+fn success_convention_from_config_file() {
+    let tmp_dir = tempdir().unwrap();
+    fs::write(tmp_dir.path().join("sumi.toml"), "convention = \"kernel\"").unwrap();
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("mm: avoid double free in slab allocator")
+        .assert()
+        .success();
+}
+
+#[test]
+fn success_convention_conventional_is_conventional_rule() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--convention")
+        .arg("conventional")
+        .arg("Not a conventional commit")
+        .assert()
+        .failure()
+        .stderr(contains("Failed to parse as a conventional commit"));
+}
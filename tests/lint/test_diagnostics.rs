@@ -0,0 +1,140 @@
+use super::contains;
+use super::run_isolated_git_sumi;
+use predicates::prelude::*;
+
+#[test]
+fn error_diagnostic_underlines_span() {
+    run_isolated_git_sumi("")
+        .args(["-P", "Add exit codes."])
+        .assert()
+        .failure()
+        .stderr(contains(
+            "error[no_period]: Header must not end with a period
+ --> line 1, column 15
+  |
+1 | Add exit codes.
+  |               ^
+  = help: remove the period at the end of the header",
+        ));
+}
+
+#[test]
+fn error_diagnostic_underlines_columns_beyond_max_header_length() {
+    run_isolated_git_sumi("")
+        .args(["--max-header-length", "10", "Add exit codes"])
+        .assert()
+        .failure()
+        .stderr(contains("1 | Add exit codes\n  |           ^^^^"))
+        .stderr(contains("= help: shorten the header to 10 characters"));
+}
+
+#[test]
+fn error_diagnostic_underlines_non_imperative_verb() {
+    run_isolated_git_sumi("")
+        .args(["-C", "-I", "feat: added exit codes"])
+        .assert()
+        .failure()
+        .stderr(contains("error[imperative]"))
+        .stderr(contains("1 | feat: added exit codes\n  |       ^^^^^"));
+}
+
+#[test]
+fn error_diagnostic_points_at_body_line() {
+    run_isolated_git_sumi("")
+        .args([
+            "--max-body-length",
+            "10",
+            "Add exit codes\n\nExplain the codes",
+        ])
+        .assert()
+        .failure()
+        .stderr(contains(" --> line 3, column 11"))
+        .stderr(contains("3 | Explain the codes\n  |           ^^^^^^^"))
+        .stderr(contains("= help: wrap the line at 10 columns"));
+}
+
+#[test]
+fn error_diagnostic_underlines_wide_characters_past_max_body_length() {
+    run_isolated_git_sumi("")
+        .args([
+            "--max-body-length",
+            "10",
+            "Add exit codes\n\n漢字漢字漢字abcdef",
+        ])
+        .assert()
+        .failure()
+        .stderr(contains(" --> line 3, column 6"))
+        .stderr(contains("3 | 漢字漢字漢字abcdef\n  |           ^^^^^^^^"));
+}
+
+#[test]
+fn error_diagnostic_points_at_body_line_of_wide_characters() {
+    let line = "漢字".repeat(11);
+    run_isolated_git_sumi("")
+        .args([
+            "--max-body-length",
+            "40",
+            &format!("Add exit codes\n\n{line}"),
+        ])
+        .assert()
+        .failure()
+        .stderr(contains("(44 > 40)"))
+        .stderr(contains(" --> line 3, column 21"))
+        .stderr(contains(format!("3 | {line}\n  | {}^^^^", " ".repeat(40))));
+}
+
+#[test]
+fn error_diagnostic_without_span() {
+    run_isolated_git_sumi("")
+        .args(["--scope-required", "-C", "feat: add exit codes"])
+        .assert()
+        .failure()
+        .stderr(contains(
+            "error[scope_required]: Commit scope is required\n  = help: add a scope",
+        ))
+        .stderr(contains("-->").not());
+}
+
+#[test]
+fn success_diagnostic_not_colored_outside_terminal() {
+    run_isolated_git_sumi("")
+        .args(["-P", "Add exit codes."])
+        .assert()
+        .failure()
+        .stderr(contains("\x1b[").not());
+}
+
+#[test]
+fn success_diagnostic_colored_with_color_always() {
+    run_isolated_git_sumi("")
+        .args(["-P", "--color", "always", "Add exit codes."])
+        .assert()
+        .failure()
+        .stderr(contains("\x1b[1;31merror[no_period]\x1b[0m"));
+
+    run_isolated_git_sumi("")
+        .args(["-P", "Add exit codes."])
+        .env("GIT_SUMI_COLOR", "always")
+        .assert()
+        .failure()
+        .stderr(contains("\x1b[1;31m"));
+}
+
+#[test]
+fn success_diagnostic_not_colored_with_color_never() {
+    run_isolated_git_sumi("")
+        .args(["-P", "--color", "never", "Add exit codes."])
+        .assert()
+        .failure()
+        .stderr(contains("\x1b[").not());
+}
+
+#[test]
+fn success_no_color_disables_auto_color() {
+    run_isolated_git_sumi("")
+        .args(["-P", "--color", "auto", "Add exit codes."])
+        .env("NO_COLOR", "1")
+        .assert()
+        .failure()
+        .stderr(contains("\x1b[").not());
+}
@@ -105,6 +105,22 @@ fn success_display_format_json() {
     assert_eq!(parsed["references"], expected_references);
 }
 
+#[test]
+fn success_display_format_ndjson() {
+    let output = run_isolated_git_sumi("")
+        .arg("-dCGqf")
+        .arg("ndjson")
+        .arg(COMMIT_MESSAGE)
+        .unwrap()
+        .stdout;
+
+    let output_str = std::str::from_utf8(&output).unwrap();
+    assert_eq!(output_str.lines().count(), 1);
+    let parsed: Value = serde_json::from_str(output_str).expect("Output is not valid JSON");
+    assert_eq!(parsed["commit_type"], "fix");
+    assert_eq!(parsed["is_breaking"], true);
+}
+
 #[test]
 fn success_json_omits_absent_fields() {
     let mut cmd = run_isolated_git_sumi("");
@@ -123,6 +139,7 @@ fn success_json_omits_absent_fields() {
         "gitmoji",
         "commit_type",
         "scope",
+        "scopes",
         "body",
         "footers",
         "is_breaking",
@@ -176,3 +193,75 @@ fn success_display_format_markdown() {
         .stdout(contains("| Breaking description | breaking description "))
         .stdout(contains("| References           | #123, ce6df36 "));
 }
+
+#[test]
+fn success_json_lists_individual_scopes() {
+    let mut cmd = run_isolated_git_sumi("");
+    let output = cmd
+        .arg("-Cdqf")
+        .arg("json")
+        .arg("feat(api, cli): add export command")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let parsed: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["scope"], "api, cli");
+    assert_eq!(parsed["scopes"], serde_json::json!(["api", "cli"]));
+}
+
+#[test]
+fn success_json_shows_header_pattern_captures() {
+    let mut cmd = run_isolated_git_sumi("");
+    let output = cmd
+        .arg("-dqf")
+        .arg("json")
+        .arg("--header-pattern")
+        .arg(r"^(?P<ticket>[A-Z]+-\d+): (?P<component>\w+)?")
+        .arg("PROJ-12: add export command")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let parsed: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        parsed["captures"],
+        serde_json::json!({"component": "add", "ticket": "PROJ-12"})
+    );
+}
+
+#[test]
+fn success_toml_and_table_show_header_pattern_captures() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("-df")
+        .arg("toml")
+        .arg("--header-pattern")
+        .arg(r"^(?P<ticket>[A-Z]+-\d+):")
+        .arg("PROJ-12: add export command")
+        .assert()
+        .success()
+        .stdout(contains("[captures]\nticket = \"PROJ-12\""));
+
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("-df")
+        .arg("table")
+        .arg("--header-pattern")
+        .arg(r"^(?P<ticket>[A-Z]+-\d+):(?P<unmatched>!)?")
+        .arg("PROJ-12: add export command")
+        .assert()
+        .success()
+        .stdout(contains("| Captures    | ticket: PROJ-12             |"));
+}
+
+#[test]
+fn success_no_captures_without_named_groups() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("-dqf")
+        .arg("json")
+        .arg("--header-pattern")
+        .arg(r"^[A-Z]+-\d+:")
+        .arg("PROJ-12: add export command")
+        .assert()
+        .success()
+        .stdout(contains("captures").not());
+}
@@ -0,0 +1,137 @@
+use super::contains;
+use super::run_isolated_git_sumi;
+use super::setup_git_repo;
+use git_sumi::{run_from, Outcome};
+use std::fs;
+
+#[test]
+fn success_exit_code_0() {
+    run_isolated_git_sumi("")
+        .args(["-C", "feat: add exit codes"])
+        .assert()
+        .code(0);
+}
+
+#[test]
+fn error_lint_failure_exit_code_1() {
+    run_isolated_git_sumi("")
+        .args(["-C", "add exit codes"])
+        .assert()
+        .code(1);
+}
+
+#[test]
+fn error_split_lines_lint_failure_exit_code_1() {
+    run_isolated_git_sumi("")
+        .args(["-C", "--split-lines", "feat: add codes\nadd exit codes"])
+        .assert()
+        .code(1);
+}
+
+#[test]
+fn error_invalid_config_exit_code_2() {
+    let tmp_dir = setup_git_repo();
+    let config_path = tmp_dir.path().join("sumi.toml");
+    fs::write(&config_path, "conventional = maybe").unwrap();
+
+    run_isolated_git_sumi("")
+        .arg("--config")
+        .arg(&config_path)
+        .arg("feat: add exit codes")
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn error_missing_config_exit_code_2() {
+    run_isolated_git_sumi("")
+        .args([
+            "--config",
+            "does-not-exist.toml",
+            "-C",
+            "feat: add exit codes",
+        ])
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn error_unreadable_config_exit_code_2() {
+    let tmp_dir = setup_git_repo();
+    let config_path = tmp_dir.path().join("sumi.toml");
+    // Not valid UTF-8, so the file can't be read as text.
+    fs::write(&config_path, b"conventional = \xff\xfe").unwrap();
+
+    run_isolated_git_sumi("")
+        .arg("--config")
+        .arg(&config_path)
+        .arg("feat: add exit codes")
+        .assert()
+        .code(2)
+        .stderr(contains("Could not read the configuration file"));
+}
+
+#[test]
+fn error_invalid_option_exit_code_2() {
+    run_isolated_git_sumi("")
+        .args(["--description-case", "title", "feat: add exit codes"])
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn error_invalid_header_pattern_exit_code_2() {
+    run_isolated_git_sumi("")
+        .args(["--config", "none", "--header-pattern", "([", "feat: x"])
+        .assert()
+        .code(2)
+        .stderr(contains("Header regex pattern '([' is invalid"));
+}
+
+#[test]
+fn error_git_failure_exit_code_3() {
+    let tmp_dir = setup_git_repo();
+
+    run_isolated_git_sumi("")
+        .current_dir(tmp_dir.path())
+        .args(["-C", "--from", "no-such-rev", "--to", "HEAD"])
+        .assert()
+        .code(3);
+}
+
+#[test]
+fn error_unreadable_commit_file_exit_code_4() {
+    run_isolated_git_sumi("")
+        .args(["-C", "--file", "does-not-exist.txt"])
+        .assert()
+        .code(4);
+}
+
+#[test]
+fn success_run_from_outcome() {
+    let outcome = run_from(["git-sumi", "--config", "none", "-C", "feat: add exit codes"]);
+    assert!(matches!(outcome, Outcome::Success));
+}
+
+#[test]
+fn error_run_from_invalid_header_pattern_is_config_error() {
+    let outcome = run_from([
+        "git-sumi",
+        "--config",
+        "none",
+        "--header-pattern",
+        "([",
+        "feat: x",
+    ]);
+    let Outcome::ConfigError(err) = outcome else {
+        panic!("Expected a configuration error, got {outcome:?}");
+    };
+    assert_eq!(err.to_string(), "Header regex pattern '([' is invalid");
+}
+
+#[test]
+fn error_run_from_unknown_argument_is_config_error() {
+    let outcome = run_from(["git-sumi", "--no-such-flag"]);
+    assert!(matches!(outcome, Outcome::ConfigError(_)));
+    assert_eq!(outcome.exit_code(), 2);
+}
@@ -0,0 +1,178 @@
+//! Tests for the `header_format` template grammar.
+
+use super::contains;
+use super::run_isolated_git_sumi;
+
+const FORMAT: &str = "{gitmoji} {type}({scope}): {description} [{ticket}]";
+
+#[test]
+fn success_header_format_binds_fields() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("-d")
+        .arg("--format")
+        .arg("json")
+        .arg("--header-format")
+        .arg(FORMAT)
+        .arg("✨ feat(api, cli): add export command [PROJ-12]")
+        .assert()
+        .success()
+        .stdout(contains("\"gitmoji\": \"✨\""))
+        .stdout(contains("\"commit_type\": \"feat\""))
+        .stdout(contains("\"scope\": \"api, cli\""))
+        .stdout(contains("\"description\": \"add export command\""))
+        .stdout(contains("\"references\": [\n    \"PROJ-12\"\n  ]"));
+}
+
+#[test]
+fn error_header_format_missing_literal() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--header-format")
+        .arg(FORMAT)
+        .arg("✨ feat api: add export command [PROJ-12]")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Header must follow the format '{gitmoji} {type}({scope}): {description} [{ticket}]': expected '(' at column 7, found ' api: add export command [PROJ-12]'",
+        ));
+}
+
+#[test]
+fn error_header_format_missing_placeholder() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--header-format")
+        .arg(FORMAT)
+        .arg("feat(api): add export command [PROJ-12]")
+        .assert()
+        .failure()
+        .stderr(contains("expected a gitmoji at column 1"));
+}
+
+#[test]
+fn error_header_format_missing_ticket() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--header-format")
+        .arg(FORMAT)
+        .arg("✨ feat(api): add export command")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "expected ' [' at column 32, found the end of the header",
+        ));
+}
+
+#[test]
+fn error_header_format_invalid_ticket() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--header-format")
+        .arg("{ticket}: {description}")
+        .arg("proj-12: add export command")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "expected a ticket, like 'PROJ-123' or '#123' at column 1",
+        ));
+}
+
+#[test]
+fn error_header_format_trailing_text() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--header-format")
+        .arg("{type}: {description} ({ticket})")
+        .arg("feat: add export command (#12) please")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "expected the end of the header at column 31, found ' please'",
+        ));
+}
+
+#[test]
+fn success_header_format_breaking() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("-d")
+        .arg("--format")
+        .arg("json")
+        .arg("--header-format")
+        .arg("{type}{breaking}: {description}")
+        .arg("feat!: drop the v1 API")
+        .assert()
+        .success()
+        .stdout(contains("\"commit_type\": \"feat\""))
+        .stdout(contains("\"is_breaking\": true"));
+}
+
+#[test]
+fn success_header_format_literal_braces() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--header-format")
+        .arg("{{{type}}} {description}")
+        .arg("{feat} add export command")
+        .assert()
+        .success();
+}
+
+#[test]
+fn error_header_format_type_not_allowed() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--header-format")
+        .arg("[{type}] {description}")
+        .arg("--types-allowed")
+        .arg("feat,fix")
+        .arg("[feta] add export command")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Invalid commit type 'feta'. Allowed types are: [feat, fix]. Did you mean 'feat'?",
+        ));
+}
+
+#[test]
+fn success_header_format_fix_type() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--header-format")
+        .arg("[{type}] {description}")
+        .arg("--types-allowed")
+        .arg("feat,fix")
+        .arg("--fix")
+        .arg("[feta] add export command")
+        .assert()
+        .success()
+        .stdout(contains("Fixed header: \"[feat] add export command\""));
+}
+
+#[test]
+fn error_header_format_unknown_placeholder() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--header-format")
+        .arg("{kind}: {description}")
+        .arg("feat: add export command")
+        .assert()
+        .code(2)
+        .stderr(contains(
+            "Header format '{kind}: {description}' is invalid: unknown placeholder '{kind}'",
+        ));
+}
+
+#[test]
+fn error_header_format_without_description() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--header-format")
+        .arg("{type}")
+        .arg("feat")
+        .assert()
+        .failure()
+        .stderr(contains("it must contain '{description}'"));
+}
+
+#[test]
+fn error_header_format_unclosed_placeholder() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--header-format")
+        .arg("{type: {description}")
+        .arg("feat: add export command")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "is invalid: unknown placeholder '{type: {description}'",
+        ));
+}
@@ -2,6 +2,7 @@ extern crate tempfile;
 
 use super::contains;
 use super::run_isolated_git_sumi;
+use predicates::prelude::*;
 use std::fs;
 use tempfile::tempdir;
 
@@ -204,8 +205,7 @@ strip_header_pattern = true
 }
 
 #[test]
-fn test_invalid_regex_pattern_graceful_fallback() {
-    // Test that invalid regex patterns don't crash and fall back to no stripping
+fn test_invalid_regex_pattern_is_a_config_error() {
     let config = r#"
 header_pattern = "[invalid_regex("
 whitespace = true
@@ -215,13 +215,15 @@ strip_header_pattern = true
     let tmp_dir = tempdir().unwrap();
     let config_path = tmp_dir.path().join("sumi.toml");
     fs::write(&config_path, config).unwrap();
-    // With invalid regex, stripping should fail gracefully and validate the full message
-    // "  fixed the bug" should fail whitespace validation (leading space)
+    // The pattern is rejected when the configuration is loaded, before any linting.
     let mut cmd = run_isolated_git_sumi("");
     cmd.arg("--config")
         .arg(config_path)
         .arg("  fixed the bug")
         .assert()
-        .failure()
-        .stderr(contains("Leading space"));
+        .code(2)
+        .stderr(contains("Leading space").not())
+        .stderr(contains(
+            "Header regex pattern '[invalid_regex(' is invalid",
+        ));
 }
@@ -0,0 +1,130 @@
+//! Tests for `[[header_patterns]]` and `header_patterns_match`.
+
+use super::contains;
+use super::run_isolated_git_sumi;
+use std::fs;
+use tempfile::{tempdir, TempDir};
+
+const PATTERNS: &str = r#"
+[[header_patterns]]
+pattern = '^\w+(\(\w+\))?: '
+message = "Use a Conventional Commits header, like 'feat(cli): add flag'"
+
+[[header_patterns]]
+pattern = '^Release v(?P<version>\d+\.\d+\.\d+)$'
+message = "Use 'Release vX.Y.Z' for releases"
+"#;
+
+fn setup_config_dir(config: &str) -> TempDir {
+    let tmp_dir = tempdir().unwrap();
+    fs::write(tmp_dir.path().join("sumi.toml"), config).unwrap();
+    tmp_dir
+}
+
+#[test]
+fn success_any_header_pattern_matches() {
+    let tmp_dir = setup_config_dir(&format!("header_patterns_match = \"any\"\n{PATTERNS}"));
+    for commit_message in ["feat(cli): add flag", "Release v1.2.3"] {
+        let mut cmd = run_isolated_git_sumi("");
+        cmd.current_dir(tmp_dir.path())
+            .arg(commit_message)
+            .assert()
+            .success();
+    }
+}
+
+#[test]
+fn error_no_header_pattern_matches() {
+    let tmp_dir = setup_config_dir(&format!("header_patterns_match = \"any\"\n{PATTERNS}"));
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("Add flag")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Header does not match any of the allowed patterns: [Use a Conventional Commits header, like 'feat(cli): add flag', Use 'Release vX.Y.Z' for releases]",
+        ));
+}
+
+#[test]
+fn error_all_header_patterns_show_messages() {
+    let tmp_dir = setup_config_dir(PATTERNS);
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("feat(cli): add flag")
+        .assert()
+        .failure()
+        .stderr(contains("Use 'Release vX.Y.Z' for releases"))
+        .stderr(contains("Found 1 linting error"));
+}
+
+#[test]
+fn success_header_patterns_match_from_command_line() {
+    let tmp_dir = setup_config_dir(PATTERNS);
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("--header-patterns-match")
+        .arg("any")
+        .arg("Release v0.4.0")
+        .assert()
+        .success();
+}
+
+#[test]
+fn success_header_pattern_combined_with_header_patterns() {
+    let tmp_dir = setup_config_dir(&format!(
+        "header_pattern = '^[A-Z]'\nheader_patterns_match = \"any\"\n{PATTERNS}"
+    ));
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("Add flag")
+        .assert()
+        .success();
+}
+
+#[test]
+fn error_header_pattern_without_message_shows_pattern() {
+    let tmp_dir = setup_config_dir(
+        r#"
+[[header_patterns]]
+pattern = '^[A-Z]+-\d+ '
+"#,
+    );
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("Add flag")
+        .assert()
+        .failure()
+        .stderr(contains(
+            r"Header does not match the required pattern: '^[A-Z]+-\d+ '",
+        ));
+}
+
+#[test]
+fn error_invalid_header_patterns_are_reported() {
+    let tmp_dir = setup_config_dir(
+        r#"
+[[header_patterns]]
+pattern = '^(feat'
+"#,
+    );
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("feat: add flag")
+        .assert()
+        .failure()
+        .stderr(contains("Header regex pattern '^(feat' is invalid"));
+}
+
+#[test]
+fn success_captures_from_matching_header_patterns() {
+    let tmp_dir = setup_config_dir(&format!("header_patterns_match = \"any\"\n{PATTERNS}"));
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("-dqf")
+        .arg("json")
+        .arg("Release v1.2.3")
+        .assert()
+        .success()
+        .stdout(contains("\"version\": \"1.2.3\""));
+}
@@ -0,0 +1,171 @@
+extern crate assert_cmd;
+extern crate tempfile;
+
+use super::run_isolated_git_sumi;
+use super::setup_git_repo;
+use assert_cmd::Command;
+use std::fs;
+use std::path::Path;
+
+fn create_commit(repo_dir: &Path, message: &str) {
+    Command::new("git")
+        .args(["commit", "--allow-empty", "-m", message])
+        .current_dir(repo_dir)
+        .assert()
+        .success();
+}
+
+fn tag(repo_dir: &Path, name: &str) {
+    Command::new("git")
+        .args(["tag", name])
+        .current_dir(repo_dir)
+        .assert()
+        .success();
+}
+
+fn next_version(repo_dir: &Path, args: &[&str]) -> String {
+    let output = run_isolated_git_sumi("next-version")
+        .current_dir(repo_dir)
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn success_next_version_bumps_by_commit_type() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+
+    create_commit(repo_dir, "feat: initial release");
+    tag(repo_dir, "v1.2.3");
+    create_commit(repo_dir, "docs: update readme");
+    assert_eq!(next_version(repo_dir, &[]), "1.2.3\n");
+
+    create_commit(repo_dir, "fix: handle empty input");
+    assert_eq!(next_version(repo_dir, &[]), "1.2.4\n");
+
+    create_commit(repo_dir, "feat: add export");
+    assert_eq!(next_version(repo_dir, &[]), "1.3.0\n");
+
+    create_commit(repo_dir, "refactor!: drop the v1 API");
+    assert_eq!(next_version(repo_dir, &[]), "2.0.0\n");
+}
+
+#[test]
+fn success_next_version_uses_the_highest_version_tag() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+
+    create_commit(repo_dir, "feat: initial release");
+    tag(repo_dir, "v1.10.0");
+    create_commit(repo_dir, "fix: handle empty input");
+    tag(repo_dir, "v1.9.0");
+    tag(repo_dir, "v2.0.0-rc.1");
+    tag(repo_dir, "nightly");
+
+    // Only the commits since v1.10.0 count.
+    assert_eq!(next_version(repo_dir, &[]), "1.10.1\n");
+}
+
+#[test]
+fn success_next_version_initial_development() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+
+    create_commit(repo_dir, "feat: initial release");
+    tag(repo_dir, "0.3.1");
+    create_commit(repo_dir, "feat: add export");
+    assert_eq!(next_version(repo_dir, &[]), "0.3.2\n");
+
+    create_commit(repo_dir, "feat!: drop the v1 API");
+    assert_eq!(next_version(repo_dir, &[]), "0.4.0\n");
+
+    fs::write(
+        repo_dir.join("sumi.toml"),
+        "[version]\ninitial_development = false\n",
+    )
+    .unwrap();
+    assert_eq!(next_version(repo_dir, &[]), "1.0.0\n");
+}
+
+#[test]
+fn success_next_version_without_tags() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+
+    create_commit(repo_dir, "fix: handle empty input");
+    fs::write(
+        repo_dir.join("sumi.toml"),
+        "[version]\ninitial_development = false\n",
+    )
+    .unwrap();
+    assert_eq!(next_version(repo_dir, &[]), "0.0.1\n");
+}
+
+#[test]
+fn success_next_version_configured_type_and_gitmoji_bumps() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    fs::write(
+        repo_dir.join("sumi.toml"),
+        r#"
+gitmoji = true
+
+[types.perf]
+bump = "minor"
+
+[types.feat]
+aliases = ["feature"]
+
+[version.gitmoji_bumps]
+recycle = "patch"
+"#,
+    )
+    .unwrap();
+
+    create_commit(repo_dir, "feat: initial release");
+    tag(repo_dir, "v1.0.0");
+    create_commit(repo_dir, "♻️ refactor: simplify the parser");
+    assert_eq!(next_version(repo_dir, &[]), "1.0.1\n");
+
+    create_commit(repo_dir, "perf: cache the config");
+    assert_eq!(next_version(repo_dir, &[]), "1.1.0\n");
+
+    tag(repo_dir, "v1.1.0");
+    create_commit(repo_dir, "feature: add export");
+    assert_eq!(next_version(repo_dir, &[]), "1.2.0\n");
+
+    tag(repo_dir, "v1.2.0");
+    create_commit(repo_dir, "💥 refactor: rename the config file");
+    assert_eq!(next_version(repo_dir, &[]), "2.0.0\n");
+}
+
+#[test]
+fn success_next_version_json_lists_bumping_commits() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+
+    create_commit(repo_dir, "feat: initial release");
+    tag(repo_dir, "v1.0.0");
+    create_commit(repo_dir, "docs: update readme");
+    create_commit(repo_dir, "feat: add export");
+
+    let output = next_version(repo_dir, &["--json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["current_version"], "1.0.0");
+    assert_eq!(json["current_tag"], "v1.0.0");
+    assert_eq!(json["next_version"], "1.1.0");
+    assert_eq!(json["next_tag"], "v1.1.0");
+    assert_eq!(json["bump"], "minor");
+    let commits = json["commits"].as_array().unwrap();
+    assert_eq!(commits.len(), 1);
+    assert_eq!(commits[0]["header"], "feat: add export");
+    assert_eq!(commits[0]["bump"], "minor");
+    assert_eq!(commits[0]["sha"].as_str().unwrap().len(), 40);
+}
@@ -81,7 +81,7 @@ fn error_bad_output_format() {
         .arg("refactor(TheYellowArrow): Implement one-way journey")
         .assert()
         .failure()
-        .stderr(contains("possible values: cli, json, table, toml"));
+        .stderr(contains("possible values: cli, json, ndjson, table, toml"));
 }
 
 #[test]
@@ -156,29 +156,29 @@ fn error_body_whitespace_issues() {
     let test_cases = [
         (
             "Dark Souls:  Blighttown\n\nBeware of toxic",
-            "Dark Souls:🟥️🟥️Blighttown",
+            "1 | Dark Souls:  Blighttown\n  |            ^^",
         ),
         (
             "Elden Ring: Gatefront Ruins \n\nNew vistas await",
-            "Elden Ring: Gatefront Ruins🟥️",
+            "1 | Elden Ring: Gatefront Ruins \n  |                            ^",
         ),
         (
             "Dark Souls: Anor Londo  \n\nHome of the gods",
-            "Dark Souls: Anor Londo🟥️🟥️",
+            "1 | Dark Souls: Anor Londo  \n  |                       ^^",
         ),
         (
             "Elden Ring: The Tree of Life\n\n Uncover  its secrets",
-            "🟥️Uncover🟥️🟥️its secrets",
+            "3 |  Uncover  its secrets\n  | ^       ^^",
         ),
     ];
-    for &(test_case, expected_issues) in &test_cases {
+    for &(test_case, expected_snippet) in &test_cases {
         let mut cmd = run_isolated_git_sumi("");
         cmd.arg("-W")
             .arg(test_case)
             .assert()
             .failure()
             .stderr(contains("Whitespace issue"))
-            .stderr(contains(expected_issues));
+            .stderr(contains(expected_snippet));
     }
 }
 
@@ -320,6 +320,86 @@ fn success_imperative_verbs() {
     }
 }
 
+#[test]
+fn error_not_using_imperative_spanish() {
+    let test_cases = [
+        ("Arreglado el error de login", "'Arreglado'. Try 'Arreglar'"),
+        (
+            "arreglando el error de login",
+            "'arreglando'. Try 'arreglar'",
+        ),
+        ("Añadido soporte para TOML", "'Añadido'. Try 'Añadir'"),
+        (
+            "actualicé las dependencias",
+            "'actualicé'. Try 'actualizar'",
+        ),
+        ("Corrigió la documentación", "'Corrigió'. Try 'Corregir'"),
+        ("incluyendo pruebas nuevas", "'incluyendo'. Try 'incluir'"),
+    ];
+
+    for (commit_message, expected_error) in test_cases {
+        let mut cmd = run_isolated_git_sumi("");
+        cmd.arg("-I")
+            .arg("--language")
+            .arg("es")
+            .arg(commit_message)
+            .assert()
+            .failure()
+            .stderr(contains(expected_error));
+    }
+}
+
+#[test]
+fn error_not_using_imperative_german() {
+    let test_cases = [
+        ("Behoben: Absturz beim Start", "'Behoben'. Try 'Beheben'"),
+        ("Fehler beim Login behoben", "'behoben'. Try 'beheben'"),
+        (
+            "Aktualisiert die Abhängigkeiten",
+            "'Aktualisiert'. Try 'Aktualisieren'",
+        ),
+        (
+            "Fügt Unterstützung für TOML hinzu",
+            "'Fügt'. Try 'Hinzufügen'",
+        ),
+        ("Tests hinzugefügt.", "'hinzugefügt'. Try 'hinzufügen'"),
+    ];
+
+    for (commit_message, expected_error) in test_cases {
+        let mut cmd = run_isolated_git_sumi("");
+        cmd.arg("-I")
+            .arg("--language")
+            .arg("de")
+            .arg(commit_message)
+            .assert()
+            .failure()
+            .stderr(contains(expected_error));
+    }
+}
+
+#[test]
+fn success_imperative_verbs_other_languages() {
+    let test_cases = [
+        ("es", "Arreglar el error de login"),
+        ("es", "añadir soporte para TOML"),
+        ("es", "Arregla el error de login"),
+        ("de", "Behebe Absturz beim Start"),
+        ("de", "Abhängigkeiten aktualisieren"),
+        ("de", "Füge Unterstützung für TOML hinzu"),
+        // English verbs are not checked when another language is selected.
+        ("es", "Updated the README"),
+    ];
+
+    for (language, commit_message) in test_cases {
+        let mut cmd = run_isolated_git_sumi("");
+        cmd.env("GIT_SUMI_LANGUAGE", language)
+            .arg("-I")
+            .arg(commit_message)
+            .assert()
+            .success();
+    }
+}
+
 #[test]
 fn error_body_too_long_custom() {
     let mut cmd = run_isolated_git_sumi("");
@@ -347,6 +427,74 @@ fn success_body_long_custom() {
         .success();
 }
 
+#[test]
+fn error_body_too_long_wide_characters() {
+    // Each CJK character and emoji takes two terminal columns.
+    let test_cases = [
+        ("漢字".repeat(11), "(44 > 40)"),
+        ("🦀".repeat(21), "(42 > 40)"),
+    ];
+    for (long_body, expected_error) in test_cases {
+        let mut cmd = run_isolated_git_sumi("");
+        cmd.arg("--max-body-length")
+            .arg("40")
+            .arg(format!("Translate the docs\n\n{long_body}"))
+            .assert()
+            .failure()
+            .stderr(contains(format!(
+                "Line number 3 is too long {expected_error}"
+            )));
+    }
+}
+
+#[test]
+fn success_body_length_exemptions() {
+    let long_text = "x".repeat(60);
+    let commit_message = format!(
+        "Document the parser
+
+See https://example.com for details.
+
+https://example.com/{long_text}
+[1]: https://example.com/{long_text}
+> Quoted {long_text}
+
+```
+fenced {long_text}
+```
+
+    indented {long_text}
+
+Signed-off-by: Someone With A Very Long Name <{long_text}@example.com>"
+    );
+
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--max-body-length")
+        .arg("40")
+        .arg("--body-length-exemptions")
+        .arg("url,code,quote,trailer")
+        .arg(commit_message)
+        .assert()
+        .success();
+}
+
+#[test]
+fn error_body_length_exemptions_only_apply_when_enabled() {
+    let long_url = format!("https://example.com/{}", "x".repeat(60));
+    let commit_message = format!("Document the parser\n\n{long_url}\n> Quoted {long_url}");
+
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--max-body-length")
+        .arg("40")
+        .arg("--body-length-exemptions")
+        .arg("url")
+        .arg(commit_message)
+        .assert()
+        .failure()
+        .stderr(contains("Line number 4 is too long"))
+        .stderr(contains("Found 1 linting error"));
+}
+
 #[test]
 fn success_body_long_custom_title_long_custom() {
     let mut cmd = run_isolated_git_sumi("");
@@ -446,6 +594,73 @@ fn error_custom_title_too_long() {
         .stderr(contains("Line number 1 is too long (51 > 50)"));
 }
 
+#[test]
+fn error_description_too_short_after_removing_prefix_and_emoji() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--conventional")
+        .arg("--min-description-length")
+        .arg("10")
+        .arg("feat: ✨ add cli")
+        .assert()
+        .failure()
+        .stderr(contains("Description is too short (7 < 10)"));
+}
+
+#[test]
+fn success_description_meets_min_length() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--conventional")
+        .arg("--min-description-length")
+        .arg("10")
+        .arg("feat: add login form")
+        .assert()
+        .success();
+}
+
+#[test]
+fn error_description_too_few_words() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--min-description-words")
+        .arg("3")
+        .arg(":bug: Fix   typo")
+        .assert()
+        .failure()
+        .stderr(contains("Description has too few words (2 < 3)"));
+}
+
+#[test]
+fn error_vague_description() {
+    let test_cases = ["Update", "Minor fixes.", "misc"];
+    for &test_case in test_cases.iter() {
+        let mut cmd = run_isolated_git_sumi("");
+        cmd.arg("--no-vague-description")
+            .arg(test_case)
+            .assert()
+            .failure()
+            .stderr(contains("is too vague"));
+    }
+}
+
+#[test]
+fn error_vague_conventional_description() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--conventional")
+        .arg("--no-vague-description")
+        .arg("chore: update")
+        .assert()
+        .failure()
+        .stderr(contains("Description 'update' is too vague"));
+}
+
+#[test]
+fn success_specific_description_with_vague_word() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--no-vague-description")
+        .arg("Update README installation steps")
+        .assert()
+        .success();
+}
+
 #[test]
 fn error_when_header_does_not_match_jira_pattern() {
     let test_cases = ["Fix issue with user login", "JIRA1234: Missing hyphen"];
@@ -500,3 +715,160 @@ fn error_not_using_imperative_with_prefix() {
             .stderr(contains("Description starts with a non-imperative verb"));
     }
 }
+
+#[test]
+fn error_forbidden_words_with_position_and_replacement() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--forbidden-words")
+        .arg("WIP: add Whitelist support\n\nThe master/slave setup is a hack.")
+        .assert()
+        .failure()
+        .stderr(contains("Forbidden word 'WIP' on line 1, column 1\n"))
+        .stderr(contains(
+            "Forbidden word 'Whitelist' on line 1, column 10. Use 'allowlist' instead",
+        ))
+        .stderr(contains(
+            "Forbidden word 'master/slave' on line 3, column 5. Use 'primary/replica' instead",
+        ));
+}
+
+#[test]
+fn success_forbidden_words_match_whole_words_only() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--forbidden-words")
+        .arg("Wipe the allowlist cache and swipe right")
+        .assert()
+        .success();
+}
+
+#[test]
+fn error_forbidden_words_extra() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.env("GIT_SUMI_FORBIDDEN_WORDS_EXTRA", "temp, master=main")
+        .arg("Merge branch MASTER into temp")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Forbidden word 'MASTER' on line 1, column 14. Use 'main' instead",
+        ))
+        .stderr(contains("Forbidden word 'temp' on line 1, column 26"));
+}
+
+#[test]
+fn error_body_required_for_type() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--body-required-types")
+        .arg("feat,fix")
+        .arg("fix: stop the clocks")
+        .assert()
+        .failure()
+        .stderr(contains("Body is required for 'fix' commits"));
+}
+
+#[test]
+fn success_body_required_for_type() {
+    let test_cases = [
+        "docs: mention the clocks",
+        "fix: stop the clocks\n\nThey were running backwards since the migration.",
+    ];
+    for commit_message in test_cases {
+        let mut cmd = run_isolated_git_sumi("");
+        cmd.arg("--body-required-types")
+            .arg("feat,fix")
+            .arg(commit_message)
+            .assert()
+            .success();
+    }
+}
+
+#[test]
+fn error_body_required_for_long_header() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--body-required-header-length")
+        .arg("20")
+        .arg("Rewrite the clock synchronisation")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Body is required when the header is longer than 20 characters (header has 33)",
+        ));
+}
+
+#[test]
+fn error_body_required_for_capture() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--header-pattern")
+        .arg(r"^(?P<ticket>[A-Z]+-\d+)(?P<breaking>!)? ")
+        .arg("--body-required-captures")
+        .arg("breaking")
+        .arg("CLK-12! Stop the clocks")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Body is required when the header pattern captures 'breaking' ('!')",
+        ));
+}
+
+#[test]
+fn success_body_required_for_capture() {
+    let test_cases = [
+        "CLK-12 Stop the clocks",
+        "CLK-12! Stop the clocks\n\nThey now stop at midnight.",
+    ];
+    for commit_message in test_cases {
+        let mut cmd = run_isolated_git_sumi("");
+        cmd.arg("--header-pattern")
+            .arg(r"^(?P<ticket>[A-Z]+-\d+)(?P<breaking>!)? ")
+            .arg("--body-required-captures")
+            .arg("breaking")
+            .arg(commit_message)
+            .assert()
+            .success();
+    }
+}
+
+#[test]
+fn error_body_too_short() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--min-body-length")
+        .arg("20")
+        .arg("Stop the clocks\n\nSee title.")
+        .assert()
+        .failure()
+        .stderr(contains("Body is too short (10 < 20)"));
+}
+
+#[test]
+fn error_body_repeats_header() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--no-repeated-header")
+        .arg("-C")
+        .arg("fix(clock): stop the clocks\n\nStop the clocks.")
+        .assert()
+        .failure()
+        .stderr(contains("Body on line 3 only repeats the header"));
+}
+
+#[test]
+fn error_inconsistent_bullet_markers() {
+    let commit_message = "Tidy the attic
+
+- Sweep the floor
+* Dust the shelves
+  + Top shelf
+  - Bottom shelf
+- Open the window";
+
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--consistent-bullets")
+        .arg(commit_message)
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Inconsistent bullet marker '*' on line 4. Use '-' like line 3",
+        ))
+        .stderr(contains(
+            "Inconsistent bullet marker '-' on line 6. Use '+' like line 5",
+        ))
+        .stderr(contains("Found 2 linting errors"));
+}
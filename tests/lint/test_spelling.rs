@@ -0,0 +1,79 @@
+use super::contains;
+use super::run_isolated_git_sumi;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn error_misspelled_description_with_suggestion() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--spelling")
+        .arg("fix: recieve messages in order")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Unknown word 'recieve' on line 1. Did you mean 'receive'",
+        ));
+}
+
+#[test]
+fn error_misspelled_body_reports_line_number() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--spelling")
+        .arg("Fix login redirect\n\nThe redirect was ignored.\nIt happend on every retry.")
+        .assert()
+        .failure()
+        .stderr(contains("Unknown word 'happend' on line 4"));
+}
+
+#[test]
+fn success_spelling_ignores_code_urls_and_references() {
+    let commit_message = "feat(cli): add `--frobnicate` flag
+
+See https://exampel.com/docs and #123, introduced in ce6df36.
+Reads snake_case and camelCase values from HTTPS_PROXY.
+
+```
+let x = wrnog();
+```
+
+    indented_code = wrnog()
+
+Co-authored-by: Jhon Doe <jhon@exmaple.com>";
+
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--spelling")
+        .arg("-C")
+        .arg(commit_message)
+        .assert()
+        .success();
+}
+
+#[test]
+fn success_spelling_with_project_dictionary() {
+    let tmp_dir = tempdir().unwrap();
+    fs::write(
+        tmp_dir.path().join(".sumi-words.txt"),
+        "# Project words\nfrobnicate\nKubernetes\n",
+    )
+    .unwrap();
+
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("--spelling")
+        .arg("Frobnicate the kubernetes manifests")
+        .assert()
+        .success();
+}
+
+#[test]
+fn error_spelling_from_config_file() {
+    let tmp_dir = tempdir().unwrap();
+    fs::write(tmp_dir.path().join("sumi.toml"), "spelling = true").unwrap();
+
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("Ad tets for the parser")
+        .assert()
+        .failure()
+        .stderr(contains("Unknown word 'tets' on line 1"));
+}
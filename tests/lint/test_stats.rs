@@ -0,0 +1,195 @@
+extern crate assert_cmd;
+extern crate tempfile;
+
+use super::contains;
+use super::run_isolated_git_sumi;
+use super::setup_git_repo;
+use assert_cmd::Command;
+use std::fs;
+use std::path::Path;
+
+fn create_commit(repo_dir: &Path, author: &str, message: &str) {
+    Command::new("git")
+        .args([
+            "-c",
+            &format!("user.name={author}"),
+            "commit",
+            "--allow-empty",
+            "-m",
+            message,
+        ])
+        .current_dir(repo_dir)
+        .assert()
+        .success();
+}
+
+/// A repository with four commits by two authors: two compliant, one that fails
+/// `no_period` and `description_case`, and one that isn't a conventional commit.
+fn setup_history() -> tempfile::TempDir {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    fs::write(
+        repo_dir.join("sumi.toml"),
+        "conventional = true\nno_period = true\ndescription_case = \"lower\"\n",
+    )
+    .unwrap();
+
+    create_commit(repo_dir, "Alice", "feat(cli): add export");
+    create_commit(repo_dir, "Alice", "fix: Handle empty input.");
+    create_commit(repo_dir, "Bob", "random stuff");
+    create_commit(repo_dir, "Bob", "feat(api)!: drop v1");
+    tmp_dir
+}
+
+fn stats(repo_dir: &Path, args: &[&str]) -> String {
+    let output = run_isolated_git_sumi("stats")
+        .current_dir(repo_dir)
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn success_stats_json() {
+    let tmp_dir = setup_history();
+    let output = stats(tmp_dir.path(), &["--format", "json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    assert_eq!(json["commits"], 4);
+    assert_eq!(json["compliant_commits"], 2);
+    assert_eq!(json["compliance_rate"], 0.5);
+    assert_eq!(json["breaking_changes"], 1);
+    assert_eq!(json["average_header_length"], 19.0);
+
+    let rules = json["rules"].as_array().unwrap();
+    let rule = |name: &str| {
+        rules
+            .iter()
+            .find(|rule| rule["rule"] == name)
+            .unwrap_or_else(|| panic!("Missing rule '{name}'"))
+    };
+    assert_eq!(rule("conventional")["violations"], 1);
+    assert_eq!(rule("no_period")["compliance_rate"], 0.75);
+    assert_eq!(rule("description_case")["violations"], 1);
+
+    assert_eq!(json["violations"].as_array().unwrap().len(), 3);
+    assert_eq!(json["types"][0]["name"], "feat");
+    assert_eq!(json["types"][0]["count"], 2);
+    assert_eq!(json["types"][1]["name"], "fix");
+    assert_eq!(json["scopes"].as_array().unwrap().len(), 2);
+    assert_eq!(json["authors"][0]["author"], "Alice");
+    assert_eq!(json["authors"][0]["compliant_commits"], 1);
+    assert_eq!(json["authors"][1]["author"], "Bob");
+    assert_eq!(json["authors"][1]["compliance_rate"], 0.5);
+}
+
+#[test]
+fn success_stats_csv() {
+    let tmp_dir = setup_history();
+    let output = stats(tmp_dir.path(), &["--format", "csv"]);
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines[0], "section,name,value,compliance_rate");
+    assert!(lines.contains(&"summary,commits,4,0.5"));
+    assert!(lines.contains(&"rule,no_period,1,0.75"));
+    assert!(lines.contains(&"type,feat,2,"));
+    assert!(lines.contains(&"author,Bob,2,0.5"));
+    // Fields with commas are quoted.
+    assert!(lines.contains(
+        &"violation,\"Failed to parse as a conventional commit: 'Missing type in the commit summary, expected `type: description`'\",1,"
+    ));
+}
+
+#[test]
+fn success_stats_table() {
+    let tmp_dir = setup_history();
+    let output = stats(tmp_dir.path(), &[]);
+
+    assert!(output.contains("│ Compliant commits     │ 2 (50.0%) │"));
+    assert!(output.contains("│ no_period        │ 1          │ 75.0%      │"));
+    assert!(output.contains("│ Header must not end with a period"));
+    assert!(output.contains("│ Alice  │ 2       │ 50.0%      │"));
+}
+
+#[test]
+fn success_stats_range() {
+    let tmp_dir = setup_history();
+    let output = stats(tmp_dir.path(), &["--from", "HEAD~2", "--format", "json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    assert_eq!(json["commits"], 2);
+    assert_eq!(json["compliant_commits"], 1);
+    assert_eq!(json["authors"].as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn success_stats_gitmoji() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    fs::write(repo_dir.join("sumi.toml"), "gitmoji = true\n").unwrap();
+
+    create_commit(repo_dir, "Alice", "✨ add export");
+    create_commit(repo_dir, "Alice", ":sparkles: add import");
+    create_commit(repo_dir, "Alice", "🐛 handle empty input");
+
+    let output = stats(repo_dir, &["--format", "json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["gitmoji"][0]["name"], "sparkles");
+    assert_eq!(json["gitmoji"][0]["count"], 2);
+    assert_eq!(json["gitmoji"][1]["name"], "bug");
+}
+
+#[test]
+fn success_stats_split_lines() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    fs::write(
+        repo_dir.join("sumi.toml"),
+        "conventional = true\nsplit_lines = true\n",
+    )
+    .unwrap();
+
+    create_commit(
+        repo_dir,
+        "Alice",
+        "feat: add export\nfix: handle empty input",
+    );
+    create_commit(repo_dir, "Alice", "feat: add import\nrandom stuff");
+
+    let output = stats(repo_dir, &["--format", "json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["commits"], 2);
+    assert_eq!(json["compliant_commits"], 1);
+}
+
+#[test]
+fn error_stats_no_rules_enabled() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+    create_commit(repo_dir, "Alice", "feat: add export");
+
+    run_isolated_git_sumi("stats")
+        .current_dir(repo_dir)
+        .assert()
+        .failure()
+        .stderr(contains("No rules enabled"));
+}
+
+#[test]
+fn error_stats_invalid_header_format() {
+    let tmp_dir = setup_history();
+    let repo_dir = tmp_dir.path();
+    fs::write(repo_dir.join("sumi.toml"), "header_format = \"{nope}\"\n").unwrap();
+
+    run_isolated_git_sumi("stats")
+        .current_dir(repo_dir)
+        .assert()
+        .code(2)
+        .stderr(contains("Header format '{nope}' is invalid"));
+}
@@ -0,0 +1,171 @@
+extern crate assert_cmd;
+extern crate tempfile;
+
+use super::contains;
+use super::run_isolated_git_sumi;
+use super::setup_git_repo;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn create_commit(repo_dir: &Path, message: &str) {
+    Command::new("git")
+        .args(["commit", "--allow-empty", "-m", message])
+        .current_dir(repo_dir)
+        .assert()
+        .success();
+}
+
+#[test]
+fn success_trailer_disables_rule() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.args([
+        "-H",
+        "20",
+        "feat: add a long header for the release\n\nSumi-Disable: max-header-length",
+    ])
+    .assert()
+    .success()
+    .stdout(contains(
+        "🔕 Disabled max_header_length: Line number 1 is too long (39 > 20)",
+    ));
+}
+
+#[test]
+fn success_trailer_disables_several_rules() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.args([
+        "-H",
+        "20",
+        "-P",
+        "feat: add a long header for the release.\n\nsumi-disable: no_period max_header_length",
+    ])
+    .assert()
+    .success();
+}
+
+#[test]
+fn error_trailer_only_disables_listed_rules() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.args([
+        "-H",
+        "20",
+        "-P",
+        "feat: add a long header for the release.\n\nSumi-Disable: max-header-length",
+    ])
+    .assert()
+    .failure()
+    .stderr(contains("error[no_period]"))
+    .stderr(contains("Found 1 linting error"));
+}
+
+#[test]
+fn error_trailer_with_unknown_rule() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.args(["-P", "feat: add export\n\nSumi-Disable: no-perod"])
+        .assert()
+        .failure()
+        .stderr(contains(
+            "error: Unknown rule 'no-perod' in the Sumi-Disable trailer
+ --> line 3, column 15
+  |
+3 | Sumi-Disable: no-perod
+  |               ^^^^^^^^",
+        ));
+}
+
+#[test]
+fn error_unsuppressible_rule() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.args([
+        "-C",
+        "--unsuppressible-rules",
+        "conventional",
+        "add export\n\nSumi-Disable: conventional",
+    ])
+    .assert()
+    .failure()
+    .stderr(contains("error[conventional]"))
+    .stderr(contains(
+        "error: Rule 'conventional' can't be disabled with a Sumi-Disable trailer",
+    ))
+    .stderr(contains(
+        "= help: fix the violation and remove 'conventional' from the trailer",
+    ));
+}
+
+#[test]
+fn error_unsuppressible_rules_wildcard_in_config() {
+    let tmp_dir = TempDir::new().unwrap();
+    fs::write(
+        tmp_dir.path().join("sumi.toml"),
+        "conventional = true\nmax_header_length = 20\nunsuppressible_rules = [\"max_*\"]\n",
+    )
+    .unwrap();
+
+    run_isolated_git_sumi("")
+        .current_dir(tmp_dir.path())
+        .arg("feat: add a long header for the release\n\nSumi-Disable: max-header-length")
+        .assert()
+        .failure()
+        .stderr(contains("error[max_header_length]"))
+        .stderr(contains("Rule 'max-header-length' can't be disabled"));
+}
+
+#[test]
+fn success_range_reports_suppressions() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+
+    create_commit(repo_dir, "feat: initial commit");
+    create_commit(
+        repo_dir,
+        "chore: release version 1.0.0 of everything\n\nSumi-Disable: max-header-length",
+    );
+
+    run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args(["--from", "HEAD~1", "--to", "HEAD", "-H", "30"])
+        .assert()
+        .success()
+        .stdout(contains(
+            "🔕 1 violation disabled by Sumi-Disable trailers:",
+        ))
+        .stdout(
+            predicate::str::is_match(
+                r"\[[0-9a-f]{7}\] max_header_length: Line number 1 is too long \(42 > 30\)",
+            )
+            .unwrap(),
+        );
+}
+
+#[test]
+fn success_range_document_lists_suppressions() {
+    let tmp_dir = setup_git_repo();
+    let repo_dir = tmp_dir.path();
+
+    create_commit(repo_dir, "feat: initial commit");
+    create_commit(
+        repo_dir,
+        "chore: release version 1.0.0 of everything\n\nSumi-Disable: max-header-length",
+    );
+
+    let output = run_isolated_git_sumi("")
+        .current_dir(repo_dir)
+        .args([
+            "--from", "HEAD~1", "--to", "HEAD", "-H", "30", "-q", "-d", "-f", "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json[0]["status"], "passed");
+    assert_eq!(json[0]["suppressed"][0]["rule"], "max_header_length");
+    assert_eq!(
+        json[0]["suppressed"][0]["error"],
+        "Line number 1 is too long (42 > 30)"
+    );
+    assert!(json[1].get("suppressed").is_none());
+}
@@ -0,0 +1,129 @@
+//! Tests for `[types.<name>]` and `[scopes.<name>]` tables: descriptions, aliases and fixes.
+
+use super::contains;
+use super::run_isolated_git_sumi;
+use std::fs;
+use tempfile::{tempdir, TempDir};
+
+const CONFIG: &str = r#"
+[types.feat]
+description = "A new feature"
+aliases = ["feature"]
+bump = "minor"
+
+[types.fix]
+description = "A bug fix"
+aliases = ["bugfix", "hotfix"]
+bump = "patch"
+
+[scopes.cli]
+description = "Command line interface"
+aliases = ["command-line"]
+
+[scopes.api]
+"#;
+
+fn setup_config_dir() -> TempDir {
+    let tmp_dir = tempdir().unwrap();
+    fs::write(tmp_dir.path().join("sumi.toml"), CONFIG).unwrap();
+    tmp_dir
+}
+
+#[test]
+fn success_defined_type_and_scope() {
+    let tmp_dir = setup_config_dir();
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("feat(cli): add export command")
+        .assert()
+        .success();
+}
+
+#[test]
+fn error_alias_suggests_canonical_type() {
+    let tmp_dir = setup_config_dir();
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("hotfix: handle empty input")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "Invalid commit type 'hotfix'. Allowed types are: [feat, fix]. Did you mean 'fix'?",
+        ));
+}
+
+#[test]
+fn error_invalid_type_suggests_closest_type() {
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.arg("--types-allowed")
+        .arg("feat,fix,docs")
+        .arg("dosc: update README")
+        .assert()
+        .failure()
+        .stderr(contains("Did you mean 'docs'?"));
+}
+
+#[test]
+fn success_fix_replaces_aliases() {
+    let tmp_dir = setup_config_dir();
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("--fix")
+        .arg("--gitmoji")
+        .arg("-dqf")
+        .arg("json")
+        .arg("✨ feature(command-line, api)!: add export command")
+        .assert()
+        .success()
+        .stdout(contains("\"commit_type\": \"feat\""))
+        .stdout(contains("\"scope\": \"cli, api\""));
+}
+
+#[test]
+fn success_fix_updates_commit_file() {
+    let tmp_dir = setup_config_dir();
+    let commit_file = tmp_dir.path().join("COMMIT_EDITMSG");
+    fs::write(&commit_file, "bugfix: handle empty input\n\n# Comment.\n").unwrap();
+
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("--fix")
+        .arg("--file")
+        .arg(&commit_file)
+        .assert()
+        .success()
+        .stdout(contains("Fixed header: \"fix: handle empty input\""));
+
+    assert_eq!(
+        fs::read_to_string(&commit_file).unwrap(),
+        "fix: handle empty input\n\n# Comment.\n"
+    );
+}
+
+#[test]
+fn success_prepare_commit_message_lists_types() {
+    let tmp_dir = setup_config_dir();
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("--prepare-commit-message")
+        .assert()
+        .success()
+        .stdout(contains("# Commit types: \n#   feat: A new feature (aliases: feature)\n#   fix: A bug fix (aliases: bugfix, hotfix)\n"))
+        .stdout(contains("#   api\n#   cli: Command line interface (aliases: command-line)"));
+}
+
+#[test]
+fn error_unknown_type_definition_key() {
+    let tmp_dir = tempdir().unwrap();
+    fs::write(
+        tmp_dir.path().join("sumi.toml"),
+        "[types.feat]\ndescripton = \"A new feature\"\n",
+    )
+    .unwrap();
+    let mut cmd = run_isolated_git_sumi("");
+    cmd.current_dir(tmp_dir.path())
+        .arg("feat: add export command")
+        .assert()
+        .failure()
+        .stderr(contains("unknown field `descripton`"));
+}